@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use solution::{Day, Params, Part, Phase, Probe};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, keeping track of how much heap is in use and of the
+/// highest it went.
+pub struct PeakAlloc;
+
+impl PeakAlloc {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Forgets the previous peak, returning the heap currently in use.
+    fn reset_peak() -> usize {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+        current
+    }
+
+    fn peak() -> usize {
+        PEAK.load(Ordering::Relaxed)
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new
+    }
+}
+
+/// How long a phase took and how much heap it needed on top of what was
+/// already allocated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: String,
+    pub nanos: u64,
+    pub peak_bytes: usize,
+}
+
+impl Measurement {
+    fn time(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    started: Option<(Instant, usize)>,
+    samples: Vec<(Phase, Duration, usize)>,
+}
+
+impl Probe for Recorder {
+    fn enter(&mut self, _phase: Phase) {
+        let base = PeakAlloc::reset_peak();
+        self.started = Some((Instant::now(), base));
+    }
+
+    fn leave(&mut self, phase: Phase) {
+        let (start, base) = self.started.take().expect("Left a phase never entered");
+        let elapsed = start.elapsed();
+        let peak = PeakAlloc::peak().saturating_sub(base);
+        self.samples.push((phase, elapsed, peak));
+    }
+}
+
+/// Runs `day` `runs` times, keeping the fastest time and the highest peak of
+/// every phase that succeeded. Failing phases are reported on stderr.
+fn measure_day(
+    day: &Day,
+    parts: &[Part],
+    input: &str,
+    params: &Params,
+    runs: u32,
+) -> Vec<Measurement> {
+    let mut best: Vec<Measurement> = Vec::new();
+    for _ in 0..runs {
+        let mut recorder = Recorder::default();
+        let answers = match panic::catch_unwind(AssertUnwindSafe(|| {
+            day.profile(input, parts, params, &mut recorder)
+        })) {
+            Ok(Ok(answers)) => answers,
+            Ok(Err(err)) => {
+                eprintln!("day{} parse: {err}", day.number);
+                return Vec::new();
+            }
+            Err(_) => {
+                eprintln!("day{}: panicked", day.number);
+                return Vec::new();
+            }
+        };
+
+        let mut failed = Vec::new();
+        for (part, answer) in parts.iter().zip(answers) {
+            if let Err(err) = answer {
+                eprintln!("day{} {part}: {err}", day.number);
+                failed.push(Phase::Solve(*part));
+            }
+        }
+        for (phase, time, peak) in recorder.samples {
+            if failed.contains(&phase) {
+                continue;
+            }
+            let nanos = u64::try_from(time.as_nanos()).unwrap_or(u64::MAX);
+            let phase = phase.to_string();
+            match best.iter_mut().find(|m| m.phase == phase) {
+                Some(m) => {
+                    m.nanos = m.nanos.min(nanos);
+                    m.peak_bytes = m.peak_bytes.max(peak);
+                }
+                None => best.push(Measurement {
+                    day: day.number,
+                    phase,
+                    nanos,
+                    peak_bytes: peak,
+                }),
+            }
+        }
+        if !failed.is_empty() {
+            break;
+        }
+    }
+    best
+}
+
+fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    for unit in ["KiB", "MiB"] {
+        if size < 1024.0 {
+            return format!("{size:.1} {unit}");
+        }
+        size /= 1024.0;
+    }
+    format!("{size:.1} GiB")
+}
+
+/// Change from `old` to `new`, in percent.
+fn change(old: f64, new: f64) -> f64 {
+    if old == 0.0 {
+        if new == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        (new - old) / old * 100.0
+    }
+}
+
+fn read_baseline(path: &Path) -> Result<HashMap<(u8, String), Measurement>, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+    let measurements: Vec<Measurement> = serde_json::from_str(&contents)
+        .map_err(|e| format!("Can't parse {}: {e}", path.display()))?;
+    Ok(measurements
+        .into_iter()
+        .map(|m| ((m.day, m.phase.clone()), m))
+        .collect())
+}
+
+pub struct Options<'a> {
+    pub days: Vec<Day>,
+    pub parts: Vec<Part>,
+    pub inputs: &'a str,
+    pub params: Params,
+    pub runs: u32,
+    pub json: Option<&'a Path>,
+    pub baseline: Option<&'a Path>,
+    pub threshold: f64,
+}
+
+/// Benchmarks every day that has an input, printing a table of the results.
+/// Returns false if anything regressed compared to the baseline or couldn't
+/// be written.
+pub fn bench(options: &Options) -> bool {
+    let baseline = match options.baseline.map(read_baseline).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let mut measurements = Vec::new();
+    for day in options.days.iter() {
+        let path = PathBuf::from(format!("day{}/inputs/{}", day.number, options.inputs));
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!("day{}: skipped, no {}", day.number, path.display());
+            continue;
+        };
+        measurements.extend(measure_day(
+            day,
+            &options.parts,
+            &input,
+            &options.params,
+            options.runs,
+        ));
+    }
+
+    let mut ok = true;
+    println!(
+        "{:<6} {:<7} {:>12} {:>12}{}",
+        "day",
+        "phase",
+        "time",
+        "peak heap",
+        if baseline.is_some() {
+            "   vs baseline"
+        } else {
+            ""
+        }
+    );
+    for m in measurements.iter() {
+        let mut line = format!(
+            "{:<6} {:<7} {:>12} {:>12}",
+            format!("day{}", m.day),
+            m.phase,
+            format!("{:.1?}", m.time()),
+            format_bytes(m.peak_bytes)
+        );
+        if let Some(old) = baseline
+            .as_ref()
+            .and_then(|b| b.get(&(m.day, m.phase.clone())))
+        {
+            let time = change(old.nanos as f64, m.nanos as f64);
+            let peak = change(old.peak_bytes as f64, m.peak_bytes as f64);
+            line += &format!("   time {time:+.1}%, peak {peak:+.1}%");
+            if time > options.threshold || peak > options.threshold {
+                line += "  REGRESSION";
+                ok = false;
+            }
+        }
+        println!("{line}");
+    }
+
+    if let Some(path) = options.json {
+        let json = serde_json::to_string_pretty(&measurements).unwrap();
+        if let Err(err) = fs::write(path, json + "\n") {
+            eprintln!("Unable to write {}: {err}", path.display());
+            ok = false;
+        }
+    }
+
+    ok
+}
@@ -0,0 +1,246 @@
+use clap::{Args, Parser, Subcommand};
+use solution::{Day, Params, Part};
+use std::fs;
+use std::io::{self, Read};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod bench;
+
+#[global_allocator]
+static ALLOC: bench::PeakAlloc = bench::PeakAlloc;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Time the parse and each part of every day that has an input
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve, as "14" or "day14"
+    #[arg(long, value_parser = parse_day, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only solve this part, as "2" or "part2"
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Solve every day, reading inputs from dayN/inputs/
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Name of the file read from dayN/inputs/ with --all
+    #[arg(long, default_value = "prod", requires = "all")]
+    inputs: String,
+
+    /// Day specific option as key=value, a lone key means key=true
+    #[arg(long = "param", value_parser = parse_param, conflicts_with = "all")]
+    params: Vec<(String, String)>,
+
+    /// Puzzle input, read from stdin when missing or "-"
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day, as "14" or "day14"
+    #[arg(long, value_parser = parse_day)]
+    day: Option<u8>,
+
+    /// Only benchmark this part, as "2" or "part2"
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Name of the file read from dayN/inputs/
+    #[arg(long, default_value = "prod")]
+    inputs: String,
+
+    /// Day specific option as key=value, a lone key means key=true
+    #[arg(long = "param", value_parser = parse_param, requires = "day")]
+    params: Vec<(String, String)>,
+
+    /// How many times to run each day, keeping the fastest run
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Also write the results as JSON to this file
+    #[arg(long)]
+    json: Option<PathBuf>,
+
+    /// JSON results of a previous run to compare against
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Increase, in percent, of time or peak heap counted as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn days() -> Vec<Day> {
+    vec![
+        Day::new::<day1::Day1>(1),
+        Day::new::<day2::Day2>(2),
+        Day::new::<day3::Day3>(3),
+        Day::new::<day4::Day4>(4),
+        Day::new::<day5::Day5>(5),
+        Day::new::<day6::Day6>(6),
+        Day::new::<day7::Day7>(7),
+        Day::new::<day8::Day8>(8),
+        Day::new::<day9::Day9>(9),
+        Day::new::<day10::Day10>(10),
+        Day::new::<day11::Day11>(11),
+        Day::new::<day12::Day12>(12),
+        Day::new::<day13::Day13>(13),
+        Day::new::<day14::Day14>(14),
+        Day::new::<day15::Day15>(15),
+        Day::new::<day16::Day16>(16),
+        Day::new::<day17::Day17>(17),
+        Day::new::<day18::Day18>(18),
+        Day::new::<day19::Day19>(19),
+        Day::new::<day20::Day20>(20),
+        Day::new::<day21::Day21>(21),
+        Day::new::<day22::Day22>(22),
+        Day::new::<day23::Day23>(23),
+        Day::new::<day24::Day24>(24),
+        Day::new::<day25::Day25>(25),
+    ]
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.strip_prefix("day")
+        .unwrap_or(s)
+        .parse()
+        .map_err(|_| format!("Invalid day {s:?}"))
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s.split_once('=').unwrap_or((s, "true"));
+    if key.is_empty() {
+        return Err(format!("Invalid param {s:?}, expected key=value"));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+fn read_input(path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) if path != Path::new("-") => fs::read_to_string(path),
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+/// Solves the requested parts of `day`, printing answers to stdout and
+/// problems to stderr. Returns whether every part was solved.
+fn run_day(day: &Day, parts: &[Part], input: &str, params: &Params) -> bool {
+    let mut ok = true;
+    for part in parts {
+        match panic::catch_unwind(|| day.solve(*part, input, params)) {
+            Ok(Ok(answer)) => {
+                println!("day{} {part}: {answer}", day.number);
+                if let Some(details) = answer.details() {
+                    println!("{details}");
+                }
+            }
+            Ok(Err(err)) => {
+                eprintln!("day{} {part}: {err}", day.number);
+                ok = false;
+            }
+            Err(_) => {
+                eprintln!("day{} {part}: panicked", day.number);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn run(args: &RunArgs) -> bool {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let days = days();
+    let params: Params = args.params.iter().cloned().collect();
+
+    if args.all {
+        let mut ok = true;
+        for day in &days {
+            let path = PathBuf::from(format!("day{}/inputs/{}", day.number, args.inputs));
+            match read_input(Some(&path)) {
+                Ok(input) => ok &= run_day(day, &parts, &input, &params),
+                Err(err) => {
+                    eprintln!(
+                        "day{}: Unable to read {}: {err}",
+                        day.number,
+                        path.display()
+                    );
+                    ok = false;
+                }
+            }
+        }
+        return ok;
+    }
+
+    let number = args.day.unwrap();
+    let Some(day) = days.iter().find(|d| d.number == number) else {
+        eprintln!("No solution for day {number}");
+        return false;
+    };
+    match read_input(args.input.as_deref()) {
+        Ok(input) => run_day(day, &parts, &input, &params),
+        Err(err) => {
+            eprintln!("Unable to read input: {err}");
+            false
+        }
+    }
+}
+
+fn run_bench(args: &BenchArgs) -> bool {
+    let days: Vec<Day> = days()
+        .into_iter()
+        .filter(|d| args.day.is_none_or(|n| n == d.number))
+        .collect();
+    if days.is_empty() {
+        eprintln!("No solution for day {}", args.day.unwrap());
+        return false;
+    }
+
+    bench::bench(&bench::Options {
+        days,
+        parts: match args.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        },
+        inputs: &args.inputs,
+        params: args.params.iter().cloned().collect(),
+        runs: args.runs,
+        json: args.json.as_deref(),
+        baseline: args.baseline.as_deref(),
+        threshold: args.threshold,
+    })
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let ok = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => run_bench(args),
+    };
+    if !ok {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states `x0, f(x0), f(f(x0)), ...` of a simulation start
+/// repeating: state `start` is the first one seen again, `period` steps later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Index of the first state equal to state `n`, which is below
+    /// `start + period`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Brent's algorithm, only ever keeping two states around.
+pub fn brent<T: Clone + Eq>(x0: &T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // Look for the period, moving the tortoise up to the hare at every power
+    // of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = step(x0);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, they meet where the cycle starts
+    let mut tortoise = x0.clone();
+    let mut hare = x0.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Floyd's tortoise and hare, only ever keeping two states around.
+pub fn floyd<T: Clone + Eq>(x0: &T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // The hare goes twice as fast, so they meet within the cycle at a
+    // multiple of the period
+    let mut tortoise = step(x0);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    let mut tortoise = x0.clone();
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Steps through the states once, remembering every one of them by its hash.
+/// Also returns the states up to where they repeat.
+pub fn hashed<T: Clone + Eq + Hash>(x0: &T, mut step: impl FnMut(&T) -> T) -> (Cycle, Vec<T>) {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states = vec![x0.clone()];
+    loop {
+        let state = states.last().unwrap();
+        if let Some(start) = seen.get(state) {
+            let cycle = Cycle {
+                start: *start,
+                period: states.len() - 1 - start,
+            };
+            states.pop();
+            return (cycle, states);
+        }
+        seen.insert(state.clone(), states.len() - 1);
+        let next = step(state);
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps through `0, 1, ..., start + period - 1` and back to `start`.
+    fn rho(start: usize, period: usize) -> impl Fn(&usize) -> usize {
+        move |x| {
+            if x + 1 < start + period {
+                x + 1
+            } else {
+                start
+            }
+        }
+    }
+
+    #[test]
+    fn finders_agree() {
+        for (start, period) in [(0, 1), (0, 5), (1, 1), (3, 1), (4, 7), (10, 3), (7, 16)] {
+            let expected = Cycle { start, period };
+            assert_eq!(brent(&0, rho(start, period)), expected);
+            assert_eq!(floyd(&0, rho(start, period)), expected);
+            let (cycle, states) = hashed(&0, rho(start, period));
+            assert_eq!(cycle, expected);
+            assert_eq!(states, (0..start + period).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn reduce_below_and_above_start() {
+        let cycle = Cycle {
+            start: 4,
+            period: 3,
+        };
+        assert_eq!(
+            (0..12).map(|n| cycle.reduce(n)).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5, 6, 4, 5, 6, 4, 5]
+        );
+        assert_eq!(cycle.reduce(1_000_000_001), 5);
+
+        let cycle = Cycle {
+            start: 0,
+            period: 1,
+        };
+        assert_eq!(cycle.reduce(0), 0);
+        assert_eq!(cycle.reduce(17), 0);
+    }
+
+    #[test]
+    fn reduced_states_match() {
+        let step = rho(5, 4);
+        let cycle = brent(&0, &step);
+        let mut state = 0;
+        for n in 0..50 {
+            let mut reduced = 0;
+            for _ in 0..cycle.reduce(n) {
+                reduced = step(&reduced);
+            }
+            assert_eq!(reduced, state, "{n}");
+            state = step(&state);
+        }
+    }
+}
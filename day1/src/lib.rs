@@ -0,0 +1,50 @@
+use solution::{Answer, Error, Result, Solution};
+
+const NUMBERS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day1;
+
+fn calibration_value(line: &[char]) -> Result<i64> {
+    let mut line_digits = line.iter().filter(|x| x.is_ascii_digit());
+    let first = line_digits
+        .next()
+        .ok_or_else(|| Error::Input(format!("No digit in {:?}", String::from_iter(line))))?;
+    let last = line_digits.next_back().unwrap_or(first);
+    Ok(format!("{first}{last}").parse().unwrap())
+}
+
+fn spelled_out(line: &str) -> Vec<char> {
+    let mut my_line: Vec<char> = line.chars().collect();
+    for (i, num) in NUMBERS.iter().enumerate() {
+        for idx in line.match_indices(num) {
+            my_line[idx.0] = char::from_digit((i + 1).try_into().unwrap(), 10).unwrap();
+        }
+    }
+    my_line
+}
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(solution::lines(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+        for line in input {
+            sum += calibration_value(&line.chars().collect::<Vec<char>>())?;
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+        for line in input {
+            sum += calibration_value(&spelled_out(line))?;
+        }
+        Ok(sum.into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day1::Day1>(env!("CARGO_MANIFEST_DIR"));
+}
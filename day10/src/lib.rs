@@ -1,7 +1,7 @@
-use ndarray::Array2;
-use std::env;
-use std::fs;
-use std::process;
+use matrix::Matrix;
+use search::Grid;
+use solution::{Answer, Error, Result, Solution};
+use std::collections::HashMap;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum Direction {
@@ -83,33 +83,29 @@ impl Pipe {
     fn is_compatible(&self, other: &Self) -> bool {
         for endpoint in self.endpoints {
             match endpoint {
-                Direction::North => {
+                Direction::North
                     if other.position[1] + 1 == self.position[1]
-                        && other.endpoints.contains(&Direction::South)
-                    {
-                        return true;
-                    }
+                        && other.endpoints.contains(&Direction::South) =>
+                {
+                    return true;
                 }
-                Direction::South => {
+                Direction::South
                     if other.position[1] == self.position[1] + 1
-                        && other.endpoints.contains(&Direction::North)
-                    {
-                        return true;
-                    }
+                        && other.endpoints.contains(&Direction::North) =>
+                {
+                    return true;
                 }
-                Direction::East => {
+                Direction::East
                     if other.position[0] == self.position[0] + 1
-                        && other.endpoints.contains(&Direction::West)
-                    {
-                        return true;
-                    }
+                        && other.endpoints.contains(&Direction::West) =>
+                {
+                    return true;
                 }
-                Direction::West => {
+                Direction::West
                     if other.position[0] + 1 == self.position[0]
-                        && other.endpoints.contains(&Direction::East)
-                    {
-                        return true;
-                    }
+                        && other.endpoints.contains(&Direction::East) =>
+                {
+                    return true;
                 }
                 _ => (),
             }
@@ -133,7 +129,7 @@ impl Pipe {
         Direction::Unknown
     }
 
-    fn to_regular(&self, neighbors: &Vec<[usize; 2]>) -> Self {
+    fn to_regular(self, neighbors: &[[usize; 2]]) -> Self {
         Pipe {
             position: self.position,
             endpoints: [
@@ -148,103 +144,80 @@ impl Pipe {
     }
 }
 
-#[derive(Debug)]
-struct Maze {
-    contents: Array2<Pipe>,
+#[derive(Debug, Clone)]
+pub struct Maze {
+    contents: Matrix<Pipe>,
     start: [usize; 2],
-    path: Vec<Pipe>,
 }
 
 impl Maze {
-    fn new(lines: &Vec<String>) -> Self {
-        let mut pipes: Vec<Pipe> = Vec::new();
-        let mut start = [0, 0];
+    fn new(lines: &[String]) -> Result<Self> {
+        let width = lines
+            .first()
+            .map(|x| x.chars().count())
+            .ok_or_else(|| Error::Input("Empty maze".to_string()))?;
+        let mut rows: Vec<Vec<Pipe>> = Vec::new();
+        let mut start = None;
         for (y, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(Error::Input(format!(
+                    "Line {} isn't as long as the first",
+                    y + 1
+                )));
+            }
+            let mut row = Vec::new();
             for (x, char) in line.chars().enumerate() {
-                pipes.push(Pipe::new(char, x, y));
-                if char == 'S' {
-                    start = [x, y];
+                if !"|-LJ7F.S".contains(char) {
+                    return Err(Error::Input(format!("Invalid tile {char:?}")));
+                }
+                row.push(Pipe::new(char, x, y));
+                if char == 'S' && start.replace([x, y]).is_some() {
+                    return Err(Error::Input("More than one start".to_string()));
                 }
             }
+            rows.push(row);
         }
+        let start = start.ok_or_else(|| Error::Input("No start".to_string()))?;
 
-        Maze {
-            contents: Array2::from_shape_vec((lines.len(), lines[0].chars().count()), pipes)
-                .unwrap(),
-            start,
-            path: Vec::new(),
-        }
-    }
-
-    fn get_neighbors(&self, x: usize, y: usize) -> Vec<[usize; 2]> {
-        let mut ret = Vec::new();
-
-        if x > 0 {
-            ret.push([x - 1, y]);
-        }
-
-        if y > 0 {
-            ret.push([x, y - 1]);
-        }
-
-        if x + 1 < self.contents.shape()[1] {
-            ret.push([x + 1, y]);
-        }
-
-        if y + 1 < self.contents.shape()[0] {
-            ret.push([x, y + 1]);
+        // The start is whichever pipe connects to the two pipes around it
+        let mut contents = Matrix::new(rows);
+        let pipe = contents[start];
+        let nexts: Vec<[usize; 2]> = contents
+            .get_neighbors(start[0], start[1])
+            .into_iter()
+            .filter(|(_, x)| pipe.is_compatible(x))
+            .map(|(pos, _)| pos)
+            .collect();
+        if nexts.len() != 2 {
+            return Err(Error::Input(format!(
+                "The start connects to {} pipes instead of 2",
+                nexts.len()
+            )));
         }
+        contents.set(start[0], start[1], pipe.to_regular(&nexts));
 
-        ret
+        Ok(Maze { contents, start })
     }
 
-    fn path_len(&mut self) -> usize {
-        let mut len = 1;
-
-        let neighbors = self.get_neighbors(self.start[0], self.start[1]);
-        let start: Pipe = *self.contents.get((self.start[1], self.start[0])).unwrap();
-        let nexts: Vec<[usize; 2]> = neighbors
-            .iter()
-            .filter(|x| start.is_compatible(self.contents.get((x[1], x[0])).unwrap()))
-            .cloned()
-            .collect();
-        self.contents[(start.position[1], start.position[0])] = start.to_regular(&nexts);
-
-        let mut cur = start;
-        let mut next: Pipe = *self.contents.get((nexts[0][1], nexts[0][0])).unwrap();
-
-        self.path.push(start.clone());
-        self.path.push(next.clone());
-
-        while next != start {
-            let compatibles: Vec<Pipe> = self
-                .get_neighbors(next.position[0], next.position[1])
-                .iter()
-                .filter(|x| !(x[0] == cur.position[0] && x[1] == cur.position[1]))
-                .map(|x| self.contents.get((x[1], x[0])).unwrap())
-                .filter(|x| next.is_compatible(x))
-                .cloned()
-                .collect();
-            if compatibles.len() > 1 {
-                panic!("More than one possible path");
-            }
-            cur = next;
-            self.path.push(next.clone());
-            next = compatibles[0];
-            len += 1;
-        }
-
-        len
+    /// Steps from the start to every pipe of the loop.
+    fn path(&self) -> HashMap<[usize; 2], usize> {
+        let grid = Grid::new(&self.contents).moves(|from, to| from.is_compatible(to));
+        search::bfs(grid.cell(self.start))
+            .distances
+            .into_iter()
+            .map(|(cell, dist)| (cell.pos, dist))
+            .collect()
     }
 
     fn enclosed_tiles(&self) -> usize {
+        let path = self.path();
         let mut ret = 0;
         for row in self.contents.rows() {
             let mut crossings = 0;
             let mut prev_corner = Direction::Unknown;
 
             for pipe in row {
-                if self.path.contains(pipe) {
+                if path.contains_key(&pipe.position) {
                     if pipe.endpoints.contains(&Direction::North)
                         && pipe.endpoints.contains(&Direction::South)
                     {
@@ -260,10 +233,9 @@ impl Maze {
                     }
                     if pipe.endpoints.contains(&Direction::West)
                         && !pipe.endpoints.contains(&Direction::East)
+                        && !pipe.endpoints.contains(&prev_corner)
                     {
-                        if !pipe.endpoints.contains(&prev_corner) {
-                            crossings += 1;
-                        }
+                        crossings += 1;
                     }
                 } else if crossings % 2 != 0 {
                     ret += 1;
@@ -274,29 +246,20 @@ impl Maze {
     }
 }
 
-fn read_input(filename: &str) -> Vec<String> {
-    let mut ret: Vec<String> = Vec::new();
-    let content = fs::read_to_string(filename).expect("Unable to read from file.");
-    for line in content.lines() {
-        ret.push(line.trim().to_string());
-    }
+pub struct Day10;
 
-    ret
-}
+impl Solution for Day10 {
+    type Input = Maze;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        println!("Wrong number of args");
-        process::exit(1);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Maze::new(&solution::lines(input))
     }
 
-    let contents = read_input(&args[1]);
-    let mut maze = Maze::new(&contents);
-    println!("{maze:?}");
-
-    maze.path_len();
+    fn part1(maze: &Self::Input) -> Result<Answer> {
+        Ok(maze.path().into_values().max().unwrap_or(0).into())
+    }
 
-    let enclose = maze.enclosed_tiles();
-    println!("{enclose:?}");
+    fn part2(maze: &Self::Input) -> Result<Answer> {
+        Ok(maze.enclosed_tiles().into())
+    }
 }
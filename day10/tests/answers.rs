@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day10::Day10>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,172 @@
+use matrix::Matrix;
+use solution::{Answer, Error, Params, Result, Solution};
+
+/// Largest expanded universe drawn, in cells.
+const MAX_DRAWN: usize = 1_000_000;
+
+trait Universe {
+    fn galaxy_positions(&self) -> Vec<[usize; 2]>;
+    fn empty_rows(&self) -> Vec<bool>;
+    fn empty_cols(&self) -> Vec<bool>;
+}
+
+impl Universe for Matrix<char> {
+    fn galaxy_positions(&self) -> Vec<[usize; 2]> {
+        let mut ret = Vec::new();
+        for (y, row) in self.rows().enumerate() {
+            for (x, elt) in row.iter().enumerate() {
+                if elt == &'#' {
+                    ret.push([x, y]);
+                }
+            }
+        }
+
+        ret
+    }
+
+    fn empty_rows(&self) -> Vec<bool> {
+        self.rows()
+            .map(|row| row.iter().all(|x| x == &'.'))
+            .collect()
+    }
+
+    fn empty_cols(&self) -> Vec<bool> {
+        self.cols()
+            .map(|col| col.iter().all(|x| x == &'.'))
+            .collect()
+    }
+}
+
+/// Where each row or column ends up once every empty one is `factor` wide,
+/// or `None` if that's too far to count.
+fn expanded(empty: &[bool], factor: usize) -> Option<Vec<usize>> {
+    let mut ret = Vec::with_capacity(empty.len());
+    let mut pos = Some(0usize);
+    for &empty in empty {
+        let at = pos?;
+        ret.push(at);
+        pos = at.checked_add(if empty { factor } else { 1 });
+    }
+    Some(ret)
+}
+
+/// Sum of the distances between every pair of coordinates, or `None` on
+/// overflow.
+fn pairwise(mut coords: Vec<usize>) -> Option<usize> {
+    coords.sort_unstable();
+    let mut before = 0usize;
+    let mut sum = 0usize;
+    for (i, &c) in coords.iter().enumerate() {
+        sum = sum.checked_add(c.checked_mul(i)? - before)?;
+        before = before.checked_add(c)?;
+    }
+    Some(sum)
+}
+
+pub struct Image {
+    universe: Matrix<char>,
+    factor: Option<usize>,
+    draw: bool,
+}
+
+impl Image {
+    /// Sum of the shortest paths between every pair of galaxies, with every
+    /// empty row and column `factor` times as wide.
+    fn distances(&self, factor: usize) -> Result<Answer> {
+        let factor = self.factor.unwrap_or(factor);
+        let sum = self.sum(factor).ok_or_else(|| {
+            Error::Param(format!(
+                "Distances are too large to sum with a factor of {factor}"
+            ))
+        })?;
+
+        let answer = Answer::from(sum);
+        if !self.draw {
+            return Ok(answer);
+        }
+        let [width, height] = self.size(factor);
+        if width.saturating_mul(height) > MAX_DRAWN {
+            return Ok(
+                answer.with_details(format!("A {width}x{height} universe is too large to draw"))
+            );
+        }
+        Ok(answer.with_details(self.draw(factor).to_string().trim_end().to_string()))
+    }
+
+    /// Sum of the shortest paths, or `None` if it doesn't fit in a `usize`.
+    fn sum(&self, factor: usize) -> Option<usize> {
+        let xs = expanded(&self.universe.empty_cols(), factor)?;
+        let ys = expanded(&self.universe.empty_rows(), factor)?;
+        let galaxies = self.universe.galaxy_positions();
+        pairwise(galaxies.iter().map(|[x, _]| xs[*x]).collect())?
+            .checked_add(pairwise(galaxies.iter().map(|[_, y]| ys[*y]).collect())?)
+    }
+
+    /// Width and height of the expanded universe.
+    fn size(&self, factor: usize) -> [usize; 2] {
+        let len = |empty: Vec<bool>| {
+            empty.iter().fold(0usize, |len, &empty| {
+                len.saturating_add(if empty { factor } else { 1 })
+            })
+        };
+        [
+            len(self.universe.empty_cols()),
+            len(self.universe.empty_rows()),
+        ]
+    }
+
+    fn draw(&self, factor: usize) -> Matrix<char> {
+        let cols = self.universe.empty_cols();
+        let rows = self.universe.empty_rows();
+        let widen = |empty: bool| if empty { factor } else { 1 };
+        self.universe
+            .rows()
+            .zip(rows.iter())
+            .flat_map(|(row, &empty)| {
+                let row: Vec<char> = row
+                    .iter()
+                    .zip(cols.iter())
+                    .flat_map(|(c, &empty)| std::iter::repeat_n(*c, widen(empty)))
+                    .collect();
+                std::iter::repeat_n(row, widen(empty))
+            })
+            .collect()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Image {
+            universe: Matrix::try_new(solution::lines(input).iter().map(|x| x.chars().collect()))
+                .map_err(|y| {
+                Error::Input(format!("Line {} isn't as long as the first", y + 1))
+            })?,
+            factor: None,
+            draw: false,
+        })
+    }
+
+    /// `factor` sets how many times wider empty rows and columns get in both
+    /// parts, and `draw` shows the expanded universe.
+    fn configure(image: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["factor", "draw"])?;
+        image.factor = params.get("factor")?;
+        if image.factor == Some(0) {
+            return Err(Error::Param("The factor has to be at least 1".to_string()));
+        }
+        image.draw = params.get("draw")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(image: &Self::Input) -> Result<Answer> {
+        image.distances(2)
+    }
+
+    fn part2(image: &Self::Input) -> Result<Answer> {
+        image.distances(1_000_000)
+    }
+}
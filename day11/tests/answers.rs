@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day11::Day11>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,237 @@
+use solution::{Answer, Error, Result, Solution};
+use std::convert::From;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpringState {
+    Operational,
+    Damaged,
+    Unknown,
+    Invalid,
+}
+
+impl From<char> for SpringState {
+    fn from(c: char) -> SpringState {
+        match c {
+            '.' => SpringState::Operational,
+            '#' => SpringState::Damaged,
+            '?' => SpringState::Unknown,
+            _ => SpringState::Invalid,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    springs: Vec<SpringState>,
+    damaged: Vec<usize>,
+}
+
+impl FromStr for Record {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::Input(format!("Invalid record {s:?}"));
+        let (left, right) = s.split_once(' ').ok_or_else(invalid)?;
+        let springs: Vec<SpringState> = left.chars().map(SpringState::from).collect();
+        if springs.contains(&SpringState::Invalid) {
+            return Err(invalid());
+        }
+        Ok(Record {
+            springs,
+            damaged: right
+                .split(',')
+                .map(|n| n.parse::<usize>().map_err(|_| invalid()))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl From<SpringState> for char {
+    fn from(state: SpringState) -> char {
+        match state {
+            SpringState::Operational => '.',
+            SpringState::Damaged => '#',
+            SpringState::Unknown => '?',
+            SpringState::Invalid => 'X',
+        }
+    }
+}
+
+/// Number of ways to finish a record from every (position, group index,
+/// current run length), filled from the end of the record backwards.
+/// Building one fails when the counts don't fit in a `u128`.
+struct Counter<'a> {
+    record: &'a Record,
+    max_run: usize,
+    table: Vec<u128>,
+}
+
+impl<'a> Counter<'a> {
+    fn new(record: &'a Record) -> Result<Self> {
+        let max_run = record.damaged.iter().copied().max().unwrap_or(0);
+        let mut counter = Counter {
+            record,
+            max_run,
+            table: vec![0; (record.springs.len() + 1) * (record.damaged.len() + 1) * (max_run + 1)],
+        };
+
+        for pos in (0..=record.springs.len()).rev() {
+            for group in 0..=record.damaged.len() {
+                for run in 0..=max_run {
+                    let ways = if pos == record.springs.len() {
+                        u128::from(counter.is_complete(group, run))
+                    } else {
+                        [SpringState::Operational, SpringState::Damaged]
+                            .iter()
+                            .filter_map(|state| counter.next(pos, group, run, *state))
+                            .try_fold(0u128, |sum, (group, run)| {
+                                sum.checked_add(counter.ways(pos + 1, group, run))
+                            })
+                            .ok_or_else(|| {
+                                Error::Input("Too many arrangements to count".to_string())
+                            })?
+                    };
+                    let idx = counter.idx(pos, group, run);
+                    counter.table[idx] = ways;
+                }
+            }
+        }
+
+        Ok(counter)
+    }
+
+    fn idx(&self, pos: usize, group: usize, run: usize) -> usize {
+        (pos * (self.record.damaged.len() + 1) + group) * (self.max_run + 1) + run
+    }
+
+    fn ways(&self, pos: usize, group: usize, run: usize) -> u128 {
+        self.table[self.idx(pos, group, run)]
+    }
+
+    fn is_complete(&self, group: usize, run: usize) -> bool {
+        let groups = self.record.damaged.len();
+        (group == groups && run == 0) || (group + 1 == groups && run == self.record.damaged[group])
+    }
+
+    /// The (group index, run length) after the spring at `pos` is set to
+    /// `state`, if that's allowed.
+    fn next(
+        &self,
+        pos: usize,
+        group: usize,
+        run: usize,
+        state: SpringState,
+    ) -> Option<(usize, usize)> {
+        let spring = self.record.springs[pos];
+        if spring != state && spring != SpringState::Unknown {
+            return None;
+        }
+
+        let expected = self.record.damaged.get(group).copied();
+        match state {
+            SpringState::Damaged if expected.is_some_and(|len| run < len) => Some((group, run + 1)),
+            SpringState::Operational if run == 0 => Some((group, 0)),
+            SpringState::Operational if expected == Some(run) => Some((group + 1, 0)),
+            _ => None,
+        }
+    }
+
+    fn total(&self) -> u128 {
+        self.ways(0, 0, 0)
+    }
+
+    /// The `index`th arrangement, with operational springs ordered before damaged ones.
+    fn arrangement(&self, mut index: u128) -> Option<Vec<SpringState>> {
+        if index >= self.total() {
+            return None;
+        }
+
+        let (mut group, mut run) = (0, 0);
+        let mut ret = Vec::with_capacity(self.record.springs.len());
+        for pos in 0..self.record.springs.len() {
+            for state in [SpringState::Operational, SpringState::Damaged] {
+                let Some((next_group, next_run)) = self.next(pos, group, run, state) else {
+                    continue;
+                };
+                let ways = self.ways(pos + 1, next_group, next_run);
+                if index < ways {
+                    ret.push(state);
+                    (group, run) = (next_group, next_run);
+                    break;
+                }
+                index -= ways;
+            }
+        }
+
+        Some(ret)
+    }
+}
+
+impl Record {
+    /// The record repeated `factor` times, springs separated by an unknown one.
+    pub fn unfold(&self, factor: usize) -> Record {
+        let mut springs = Vec::new();
+        for i in 0..factor {
+            if i > 0 {
+                springs.push(SpringState::Unknown);
+            }
+            springs.extend(self.springs.iter());
+        }
+
+        Record {
+            springs,
+            damaged: self.damaged.repeat(factor),
+        }
+    }
+
+    /// Number of ways to replace the unknown springs to match the damaged
+    /// groups, failing if there are more than a `u128` holds.
+    pub fn count(&self) -> Result<u128> {
+        Ok(Counter::new(self)?.total())
+    }
+
+    /// The `index`th possible arrangement, e.g. to sample one at random
+    /// below `count()`.
+    pub fn arrangement(&self, index: u128) -> Result<Option<Vec<SpringState>>> {
+        Ok(Counter::new(self)?.arrangement(index))
+    }
+
+    /// Every possible arrangement, in the same order as `arrangement`.
+    pub fn arrangements(&self) -> Result<impl Iterator<Item = Vec<SpringState>> + '_> {
+        let counter = Counter::new(self)?;
+        Ok((0..counter.total()).map(move |i| counter.arrangement(i).unwrap()))
+    }
+}
+
+/// Sum of the arrangement counts of every record.
+fn total(records: impl Iterator<Item = Record>) -> Result<Answer> {
+    let mut sum = 0u128;
+    for record in records {
+        sum = sum
+            .checked_add(record.count()?)
+            .ok_or_else(|| Error::Input("Too many arrangements to count".to_string()))?;
+    }
+    Ok(sum.into())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        solution::lines(input)
+            .iter()
+            .map(|s| Record::from_str(s))
+            .collect()
+    }
+
+    fn part1(records: &Self::Input) -> Result<Answer> {
+        total(records.iter().cloned())
+    }
+
+    fn part2(records: &Self::Input) -> Result<Answer> {
+        total(records.iter().map(|r| r.unfold(5)))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day12::Day12>(env!("CARGO_MANIFEST_DIR"));
+}
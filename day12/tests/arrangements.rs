@@ -0,0 +1,79 @@
+use day12::Record;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+fn to_string(arrangement: &[day12::SpringState]) -> String {
+    arrangement.iter().map(|x| char::from(*x)).collect()
+}
+
+#[test]
+fn enumerates_every_arrangement_in_order() {
+    let record = Record::from_str("?###???????? 3,2,1").unwrap();
+    let arrangements: Vec<String> = record
+        .arrangements()
+        .unwrap()
+        .map(|x| to_string(&x))
+        .collect();
+
+    assert_eq!(
+        arrangements,
+        [
+            ".###....##.#",
+            ".###...##..#",
+            ".###...##.#.",
+            ".###..##...#",
+            ".###..##..#.",
+            ".###..##.#..",
+            ".###.##....#",
+            ".###.##...#.",
+            ".###.##..#..",
+            ".###.##.#...",
+        ]
+    );
+    assert_eq!(record.count().unwrap(), 10);
+    assert_eq!(arrangements.iter().collect::<HashSet<_>>().len(), 10);
+    for arrangement in arrangements.iter() {
+        let groups: Vec<usize> = arrangement
+            .split('.')
+            .filter(|x| !x.is_empty())
+            .map(|x| x.len())
+            .collect();
+        assert_eq!(groups, [3, 2, 1], "{arrangement}");
+        assert!(arrangement
+            .chars()
+            .zip("?###????????".chars())
+            .all(|(a, r)| r == '?' || a == r));
+    }
+}
+
+#[test]
+fn samples_by_index() {
+    let record = Record::from_str("?###???????? 3,2,1").unwrap();
+    assert_eq!(
+        record
+            .arrangement(3)
+            .unwrap()
+            .map(|x| to_string(&x))
+            .as_deref(),
+        Some(".###..##...#")
+    );
+    assert_eq!(record.arrangement(10).unwrap(), None);
+}
+
+#[test]
+fn counts_past_u64() {
+    // 20 groups of 1 among 399 springs: C(380, 20) ways.
+    let record = Record::from_str("??????????????????? 1").unwrap();
+    assert_eq!(
+        record.unfold(20).count().unwrap(),
+        974063881786150931723766238895475
+    );
+}
+
+#[test]
+fn fails_when_the_count_overflows() {
+    let record = Record::from_str("??????????????????? 1").unwrap();
+    assert!(record.unfold(30).count().is_err());
+    assert!(record.unfold(30).arrangement(0).is_err());
+    assert!(record.unfold(30).arrangements().is_err());
+}
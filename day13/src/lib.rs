@@ -0,0 +1,138 @@
+use matrix::Matrix;
+use solution::{Answer, Error, Result, Solution};
+
+#[derive(Debug, Eq, PartialEq)]
+enum ReflectionType {
+    Vertical,
+    Horizontal,
+}
+
+trait Mirrors {
+    fn potential_reflections(&self, smudges: usize) -> (Vec<usize>, Vec<usize>);
+    fn reflection(&self, idx: usize, reflection: ReflectionType, smudges: usize) -> Option<usize>;
+    fn summary(&self, smudges: usize) -> Option<usize>;
+}
+
+fn differences<'a>(
+    left: impl IntoIterator<Item = &'a char>,
+    right: impl IntoIterator<Item = &'a char>,
+) -> usize {
+    left.into_iter().zip(right).filter(|(l, r)| l != r).count()
+}
+
+impl Mirrors for Matrix<char> {
+    fn potential_reflections(&self, smudges: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut col_refs = Vec::new();
+        let mut row_refs = Vec::new();
+        for x in 0..self.num_cols() - 1 {
+            if differences(self.col(x).unwrap(), self.col(x + 1).unwrap()) <= smudges {
+                col_refs.push(x);
+            }
+        }
+
+        for y in 0..self.num_rows() - 1 {
+            if differences(self.row(y).unwrap(), self.row(y + 1).unwrap()) <= smudges {
+                row_refs.push(y);
+            }
+        }
+        (col_refs, row_refs)
+    }
+
+    /// A reflection only counts if fixing exactly `smudges` cells makes it perfect.
+    fn reflection(&self, idx: usize, reflection: ReflectionType, smudges: usize) -> Option<usize> {
+        let endidx = match reflection {
+            ReflectionType::Horizontal => self.num_rows() - 1,
+            ReflectionType::Vertical => self.num_cols() - 1,
+        };
+        let mut left_idx = idx;
+        let mut right_idx = idx + 1;
+        let mut found = 0;
+
+        loop {
+            found += match reflection {
+                ReflectionType::Horizontal => {
+                    differences(self.row(left_idx).unwrap(), self.row(right_idx).unwrap())
+                }
+                ReflectionType::Vertical => {
+                    differences(self.col(left_idx).unwrap(), self.col(right_idx).unwrap())
+                }
+            };
+            if found > smudges {
+                return None;
+            }
+
+            if left_idx == 0 || right_idx == endidx {
+                break;
+            }
+
+            left_idx -= 1;
+            right_idx += 1;
+        }
+
+        if found != smudges {
+            return None;
+        }
+
+        match reflection {
+            ReflectionType::Horizontal => Some(100 * (idx + 1)),
+            ReflectionType::Vertical => Some(idx + 1),
+        }
+    }
+
+    fn summary(&self, smudges: usize) -> Option<usize> {
+        let (vert, horiz) = self.potential_reflections(smudges);
+        let vert_ref: Vec<usize> = vert
+            .iter()
+            .filter_map(|x| self.reflection(*x, ReflectionType::Vertical, smudges))
+            .collect();
+        let horiz_ref: Vec<usize> = horiz
+            .iter()
+            .filter_map(|x| self.reflection(*x, ReflectionType::Horizontal, smudges))
+            .collect();
+
+        if vert_ref.len() == 1 {
+            Some(vert_ref[0])
+        } else if horiz_ref.len() == 1 {
+            Some(horiz_ref[0])
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Day13;
+
+fn summarize(patterns: &[Matrix<char>], smudges: usize) -> Result<Answer> {
+    let mut total = 0;
+    for (i, pattern) in patterns.iter().enumerate() {
+        total += pattern
+            .summary(smudges)
+            .ok_or_else(|| Error::Input(format!("No single reflection in pattern {}", i + 1)))?;
+    }
+    Ok(total.into())
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Matrix<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        solution::lines(input)
+            .split(|x| x.is_empty())
+            .filter(|x| !x.is_empty())
+            .enumerate()
+            .map(|(i, x)| {
+                Matrix::try_new(x.iter().map(|x| x.chars().collect())).map_err(|_| {
+                    Error::Input(format!("Pattern {} has rows of different lengths", i + 1))
+                })
+            })
+            .collect()
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<Answer> {
+        summarize(patterns, 0)
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<Answer> {
+        summarize(patterns, 1)
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day13::Day13>(env!("CARGO_MANIFEST_DIR"));
+}
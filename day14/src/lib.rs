@@ -1,8 +1,6 @@
 use matrix::Matrix;
-use std::env;
-use std::fs;
-use std::ops::Index;
-use std::process;
+use solution::{Answer, Error, Params, Result, Solution};
+use std::str::FromStr;
 
 #[derive(Clone, Copy)]
 enum TiltDirection {
@@ -56,7 +54,7 @@ impl Platform for Matrix<char> {
     }
 
     fn move_rock(&mut self, direction: TiltDirection, x: usize, y: usize) -> (usize, usize) {
-        if self.get(x, y) == Some('.') || self.get(x, y) == Some('#') {
+        if self.get(x, y) == Some(&'.') || self.get(x, y) == Some(&'#') {
             return (x, y);
         }
 
@@ -69,17 +67,14 @@ impl Platform for Matrix<char> {
 
         let mut new_y = i32::try_from(y).unwrap();
         let mut new_x = i32::try_from(x).unwrap();
-        let mut old_x = new_x;
-        let mut old_y = new_y;
 
         loop {
-            old_x = new_x;
-            old_y = new_y;
-            new_x = new_x + x_transform;
-            new_y = new_y + y_transform;
+            let (old_x, old_y) = (new_x, new_y);
+            new_x += x_transform;
+            new_y += y_transform;
 
-            if self.get(new_x.try_into().unwrap(), new_y.try_into().unwrap()) == Some('#')
-                || self.get(new_x.try_into().unwrap(), new_y.try_into().unwrap()) == Some('O')
+            if self.get(new_x.try_into().unwrap(), new_y.try_into().unwrap()) == Some(&'#')
+                || self.get(new_x.try_into().unwrap(), new_y.try_into().unwrap()) == Some(&'O')
             {
                 return (old_x.try_into().unwrap(), old_y.try_into().unwrap());
             }
@@ -114,70 +109,93 @@ impl Platform for Matrix<char> {
     }
 }
 
-fn read_input(filename: &str) -> Vec<String> {
-    let mut ret: Vec<String> = Vec::new();
-    let content = fs::read_to_string(filename).expect("Unable to read from file.");
-    for line in content.lines() {
-        ret.push(line.trim().to_string());
+/// How part2 finds where the spin cycles start repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Finder {
+    Brent,
+    Floyd,
+    Hashed,
+}
+
+impl FromStr for Finder {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "brent" => Ok(Finder::Brent),
+            "floyd" => Ok(Finder::Floyd),
+            "hashed" => Ok(Finder::Hashed),
+            _ => Err(Error::Param(format!(
+                "Unknown finder {s:?}, expected brent, floyd or hashed"
+            ))),
+        }
     }
+}
 
-    ret
+pub struct Dish {
+    platform: Matrix<char>,
+    cycles: usize,
+    finder: Finder,
 }
 
-fn parse_input(contents: &Vec<String>) -> Matrix<char> {
-    contents.iter().map(|x| x.chars().collect()).collect()
+fn spin(platform: &Matrix<char>) -> Matrix<char> {
+    let mut ret = platform.clone();
+    ret.cycle();
+    ret
 }
 
-fn find_pattern(idx: usize, loads: &Vec<usize>) -> Option<Vec<usize>> {
-    let mut offset = 1;
-    let pat_start = loads[idx];
-    while loads[idx - offset] == loads[loads.len() - offset]
-        && loads[loads.len() - offset] != pat_start
-    {
-        offset += 1;
-    }
-    if offset == 1 || loads[loads.len() - offset] != pat_start {
-        return None;
-    }
+pub struct Day14;
 
-    Some(loads[loads.len() - offset..].iter().cloned().collect())
-}
+impl Solution for Day14 {
+    type Input = Dish;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let platform = Matrix::try_new(solution::lines(input).iter().map(|x| x.chars().collect()))
+            .map_err(|y| Error::Input(format!("Line {} isn't as long as the first", y + 1)))?;
+        if platform.num_rows() == 0 || platform.num_cols() == 0 {
+            return Err(Error::Input("Empty platform".to_string()));
+        }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        println!("Wrong number of args");
-        process::exit(1);
+        Ok(Dish {
+            platform,
+            cycles: 1000000000,
+            finder: Finder::Brent,
+        })
     }
 
-    let contents = read_input(&args[1]);
-    let mut platform = parse_input(&contents);
-    println!("{platform}");
+    /// `cycles` sets how many spin cycles part2 runs, `finder` how it finds
+    /// them repeating: `brent`, `floyd` or `hashed`.
+    fn configure(dish: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["cycles", "finder"])?;
+        if let Some(cycles) = params.get("cycles")? {
+            dish.cycles = cycles;
+        }
+        if let Some(finder) = params.get("finder")? {
+            dish.finder = finder;
+        }
+        Ok(())
+    }
 
-    let mut cycles = 0;
-    let mut loads: Vec<usize> = Vec::new();
+    fn part1(dish: &Self::Input) -> Result<Answer> {
+        let mut platform = dish.platform.clone();
+        platform.tilt(TiltDirection::North);
+        Ok(platform.load().into())
+    }
 
-    loop {
-        platform.cycle();
-        cycles += 1;
-        let load = platform.load();
-        if loads.contains(&load) {
-            if let Some(pattern) =
-                find_pattern(loads.iter().position(|l| l == &load).unwrap(), &loads)
-            {
-                println!(
-                    "Found pattern {pattern:?} of len {} at {}",
-                    pattern.len(),
-                    loads.iter().position(|l| l == &load).unwrap()
-                );
-                println!("{loads:?}");
-                break;
+    fn part2(dish: &Self::Input) -> Result<Answer> {
+        let cycle = match dish.finder {
+            Finder::Brent => cycle::brent(&dish.platform, spin),
+            Finder::Floyd => cycle::floyd(&dish.platform, spin),
+            Finder::Hashed => {
+                let (cycle, states) = cycle::hashed(&dish.platform, spin);
+                return Ok(states[cycle.reduce(dish.cycles)].load().into());
             }
+        };
+
+        let mut platform = dish.platform.clone();
+        for _ in 0..cycle.reduce(dish.cycles) {
+            platform.cycle();
         }
-        loads.push(load);
-        if cycles == 1000000000 {
-            break;
-        }
+        Ok(platform.load().into())
     }
-    println!("{}", platform.load());
 }
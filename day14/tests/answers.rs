@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day14::Day14>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,158 @@
+use solution::{Answer, Error, Result, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct Step {
+    label: String,
+    operation: char,
+    lens: Option<i32>,
+}
+
+#[derive(Debug)]
+struct StepErr;
+impl FromStr for Step {
+    type Err = StepErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let label = s
+            .chars()
+            .take_while(|x| x.is_alphabetic())
+            .collect::<String>();
+        let operation = s.chars().find(|x| !x.is_alphabetic()).ok_or(StepErr)?;
+        let lens: Option<i32> = s.split_once(operation).unwrap().1.parse::<i32>().ok();
+        Ok(Step {
+            label,
+            operation,
+            lens,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Lens {
+    label: String,
+    focal_len: i32,
+}
+
+impl Lens {
+    fn new(label: &str, focal_len: i32) -> Self {
+        Lens {
+            label: label.to_string(),
+            focal_len,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Box {
+    id: usize,
+    lenses: Vec<Option<Lens>>,
+}
+
+impl fmt::Display for Box {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Box {}: {}",
+            self.id,
+            self.lenses
+                .iter()
+                .map(|x| match x {
+                    Some(lens) => format!("[{} {}]", lens.label, lens.focal_len),
+                    None => "".to_string(),
+                })
+                .collect::<String>(),
+        )
+    }
+}
+
+impl Box {
+    fn new(id: usize) -> Self {
+        Box {
+            id,
+            lenses: Vec::new(),
+        }
+    }
+
+    fn lens_idx(&self, label: &str) -> Option<usize> {
+        self.lenses.iter().position(|x| match x {
+            Some(lens) => lens.label == label,
+            None => false,
+        })
+    }
+
+    fn remove_lens(&mut self, label: &str) {
+        let lens_pos = self.lens_idx(label);
+        if let Some(idx) = lens_pos {
+            for i in (0..idx).rev() {
+                self.lenses[i + 1] = self.lenses[i].clone();
+            }
+            self.lenses[0] = None;
+        }
+    }
+
+    fn add_lens(&mut self, lens: Lens) {
+        let lens_pos = self.lens_idx(&lens.label);
+        if let Some(idx) = lens_pos {
+            self.lenses[idx] = Some(lens);
+        } else {
+            self.lenses.push(Some(lens));
+        }
+    }
+
+    fn power(&self) -> i32 {
+        let mut sum = 0;
+        for (i, l) in (1..).zip(self.lenses.iter().flatten()) {
+            sum += (i32::try_from(self.id).unwrap() + 1) * i * l.focal_len;
+        }
+        sum
+    }
+}
+
+fn hash(s: &str) -> u8 {
+    let mut ret: u8 = 0;
+    for c in s.bytes() {
+        ret = ret.wrapping_add(c);
+        ret = ret.wrapping_mul(17);
+    }
+
+    ret
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().split(',').map(|x| x.to_string()).collect())
+    }
+
+    fn part1(steps: &Self::Input) -> Result<Answer> {
+        Ok(steps
+            .iter()
+            .map(|x| usize::from(hash(x)))
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(steps: &Self::Input) -> Result<Answer> {
+        let steps = steps
+            .iter()
+            .map(|x| Step::from_str(x).map_err(|_| Error::Input(format!("Invalid step {x:?}"))))
+            .collect::<Result<Vec<Step>>>()?;
+        let mut boxes = (0..256).map(Box::new).collect::<Vec<_>>();
+
+        for step in steps {
+            let box_idx = usize::from(hash(&step.label));
+            match step.operation {
+                '-' => boxes[box_idx].remove_lens(&step.label),
+                '=' => boxes[box_idx].add_lens(Lens::new(&step.label, step.lens.unwrap())),
+                _ => (),
+            }
+        }
+
+        Ok(boxes.iter().map(|x| x.power()).sum::<i32>().into())
+    }
+}
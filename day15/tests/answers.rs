@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day15::Day15>(env!("CARGO_MANIFEST_DIR"));
+}
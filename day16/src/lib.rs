@@ -1,7 +1,5 @@
 use matrix::Matrix;
-use std::env;
-use std::fs;
-use std::process;
+use solution::{Answer, Error, Result, Solution};
 
 #[derive(Debug, Clone, Copy)]
 enum MirrorType {
@@ -57,7 +55,7 @@ impl From<char> for CellType {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct ContraptionCell {
+pub struct ContraptionCell {
     celltype: CellType,
     visited: bool,
 }
@@ -72,15 +70,10 @@ impl From<char> for ContraptionCell {
 }
 
 trait Visited {
-    fn visited(&mut self, x: usize, y: usize);
     fn count_visited(&self) -> usize;
 }
 
 impl Visited for Matrix<ContraptionCell> {
-    fn visited(&mut self, x: usize, y: usize) {
-        self.get_mut(x, y).unwrap().visited = true;
-    }
-
     fn count_visited(&self) -> usize {
         self.rows()
             .fold(0, |acc, x| acc + x.iter().filter(|c| c.visited).count())
@@ -165,7 +158,7 @@ impl Beam {
                 if self.velocity[0] != 0 {
                     self.velocity[0] = 0;
                     self.velocity[1] = -1;
-                    let mut newbeam = self.clone();
+                    let mut newbeam = *self;
                     newbeam.velocity[0] = 0;
                     newbeam.velocity[1] = 1;
                     Some(newbeam)
@@ -177,7 +170,7 @@ impl Beam {
                 if self.velocity[1] != 0 {
                     self.velocity[0] = -1;
                     self.velocity[1] = 0;
-                    let mut newbeam = self.clone();
+                    let mut newbeam = *self;
                     newbeam.velocity[0] = 1;
                     newbeam.velocity[1] = 0;
                     Some(newbeam)
@@ -203,30 +196,11 @@ impl Beam {
     }
 }
 
-fn read_input(filename: &str) -> Vec<String> {
-    let mut ret: Vec<String> = Vec::new();
-    let content = fs::read_to_string(filename).expect("Unable to read from file.");
-    for line in content.lines() {
-        ret.push(line.trim().to_string());
-    }
-
-    ret
-}
-
-fn parse_input(contents: &Vec<String>) -> Matrix<ContraptionCell> {
-    Matrix::from_iter(
-        contents
-            .iter()
-            .map(|row| row.chars().map(|c| ContraptionCell::from(c)).collect()),
-    )
-}
-
 fn energize(
     contraption: &mut Matrix<ContraptionCell>,
     start: [usize; 2],
     velocity: [i32; 2],
 ) -> usize {
-    // TODO: make beam
     let mut beams = vec![Beam::new(
         start,
         velocity,
@@ -267,44 +241,62 @@ fn energize(
         energized.push(visited);
     }
 
-    /*for row in contraption.rows() {
-        println!(
-            "{}",
-            row.iter()
-                .map(|c| if c.visited { '#' } else { '.' })
-                .collect::<String>()
-        );
-    }*/
     contraption.count_visited()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        println!("Wrong number of args");
-        process::exit(1);
-    }
+pub struct Day16;
 
-    let contents = read_input(&args[1]);
-    let contraption = parse_input(&contents);
-    let mut energies = Vec::new();
-    for x in 0..contraption.num_cols() {
-        energies.push(energize(&mut contraption.clone(), [x, 0], [0, 1]));
-        energies.push(energize(
-            &mut contraption.clone(),
-            [x, contraption.num_cols() - 1],
-            [0, -1],
-        ))
+impl Solution for Day16 {
+    type Input = Matrix<ContraptionCell>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rows = solution::lines(input)
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|c| match ContraptionCell::from(c) {
+                        ContraptionCell {
+                            celltype: CellType::Invalid,
+                            ..
+                        } => Err(Error::Input(format!("Invalid cell {c:?}"))),
+                        cell => Ok(cell),
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<ContraptionCell>>>>()?;
+        let contraption = Matrix::try_new(rows)
+            .map_err(|y| Error::Input(format!("Line {} isn't as long as the first", y + 1)))?;
+        if contraption.num_rows() == 0 || contraption.num_cols() == 0 {
+            return Err(Error::Input("Empty contraption".to_string()));
+        }
+
+        Ok(contraption)
     }
 
-    for y in 0..contraption.num_rows() {
-        energies.push(energize(&mut contraption.clone(), [0, y], [1, 0]));
-        energies.push(energize(
-            &mut contraption.clone(),
-            [contraption.num_cols() - 1, y],
-            [-1, 0],
-        ));
+    fn part1(contraption: &Self::Input) -> Result<Answer> {
+        Ok(energize(&mut contraption.clone(), [0, 0], [1, 0]).into())
     }
 
-    println!("{}", energies.iter().max().unwrap());
+    fn part2(contraption: &Self::Input) -> Result<Answer> {
+        let mut energies = Vec::new();
+        for x in 0..contraption.num_cols() {
+            energies.push(energize(&mut contraption.clone(), [x, 0], [0, 1]));
+            energies.push(energize(
+                &mut contraption.clone(),
+                [x, contraption.num_rows() - 1],
+                [0, -1],
+            ))
+        }
+
+        for y in 0..contraption.num_rows() {
+            energies.push(energize(&mut contraption.clone(), [0, y], [1, 0]));
+            energies.push(energize(
+                &mut contraption.clone(),
+                [contraption.num_cols() - 1, y],
+                [-1, 0],
+            ));
+        }
+
+        Ok((*energies.iter().max().unwrap()).into())
+    }
 }
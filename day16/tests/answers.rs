@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day16::Day16>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,251 @@
+use matrix::Matrix;
+use search::SearchState;
+use solution::{Answer, Error, Params, Result, Solution};
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    fn turns(self) -> [Direction; 2] {
+        match self {
+            Direction::North | Direction::South => [Direction::East, Direction::West],
+            Direction::East | Direction::West => [Direction::North, Direction::South],
+        }
+    }
+
+    fn step(self, pos: [usize; 2], map: &Matrix<u32>) -> Option<[usize; 2]> {
+        let [x, y] = pos;
+        let next = match self {
+            Direction::North => [x, y.checked_sub(1)?],
+            Direction::South => [x, y + 1],
+            Direction::East => [x + 1, y],
+            Direction::West => [x.checked_sub(1)?, y],
+        };
+        map.get(next[0], next[1]).map(|_| next)
+    }
+
+    fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::South => 'v',
+            Direction::East => '>',
+            Direction::West => '<',
+        }
+    }
+}
+
+/// How many blocks a crucible has to and can go in a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    min: usize,
+    max: usize,
+}
+
+const CRUCIBLE: Crucible = Crucible { min: 1, max: 3 };
+const ULTRA_CRUCIBLE: Crucible = Crucible { min: 4, max: 10 };
+
+/// A crucible somewhere in the city, with how far it's gone straight.
+#[derive(Clone, Copy)]
+struct Cart<'a> {
+    map: &'a Matrix<u32>,
+    crucible: Crucible,
+    pos: [usize; 2],
+    heading: Option<Direction>,
+    run: usize,
+}
+
+impl PartialEq for Cart<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.pos, self.heading, self.run) == (other.pos, other.heading, other.run)
+    }
+}
+
+impl Eq for Cart<'_> {}
+
+impl Hash for Cart<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.pos, self.heading, self.run).hash(state);
+    }
+}
+
+impl Cart<'_> {
+    fn goal(&self) -> [usize; 2] {
+        [self.map.num_cols() - 1, self.map.num_rows() - 1]
+    }
+}
+
+impl SearchState for Cart<'_> {
+    type Cost = u32;
+
+    fn successors(&self) -> Vec<(Self, u32)> {
+        let directions = match self.heading {
+            None => Direction::ALL.to_vec(),
+            Some(heading) => {
+                let mut ret = Vec::new();
+                if self.run < self.crucible.max {
+                    ret.push(heading);
+                }
+                if self.run >= self.crucible.min {
+                    ret.extend(heading.turns());
+                }
+                ret
+            }
+        };
+
+        directions
+            .into_iter()
+            .filter_map(|dir| {
+                let pos = dir.step(self.pos, self.map)?;
+                let run = if self.heading == Some(dir) {
+                    self.run + 1
+                } else {
+                    1
+                };
+                let next = Cart {
+                    pos,
+                    heading: Some(dir),
+                    run,
+                    ..*self
+                };
+                Some((next, self.map[pos]))
+            })
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.pos == self.goal() && self.run >= self.crucible.min
+    }
+
+    fn heuristic(&self) -> u32 {
+        let [gx, gy] = self.goal();
+        u32::try_from(gx - self.pos[0] + gy - self.pos[1]).unwrap()
+    }
+}
+
+pub struct City {
+    map: Matrix<u32>,
+    min: Option<usize>,
+    max: Option<usize>,
+    route: bool,
+}
+
+impl City {
+    fn crucible(&self, default: Crucible) -> Result<Crucible> {
+        let crucible = Crucible {
+            min: self.min.unwrap_or(default.min),
+            max: self.max.unwrap_or(default.max),
+        };
+        if crucible.min == 0 || crucible.min > crucible.max {
+            return Err(Error::Param(format!(
+                "Need 0 < min <= max, got {}..={}",
+                crucible.min, crucible.max
+            )));
+        }
+        Ok(crucible)
+    }
+
+    /// Least heat lost going from the top left to the bottom right.
+    fn least_heat_loss(&self, default: Crucible) -> Result<Answer> {
+        let start = Cart {
+            map: &self.map,
+            crucible: self.crucible(default)?,
+            pos: [0, 0],
+            heading: None,
+            run: 0,
+        };
+        let result = search::astar(start);
+        let (Some(cost), Some(path)) = (result.cost(), result.path()) else {
+            return Err(Error::Input(
+                "The crucible can't reach the factory".to_string(),
+            ));
+        };
+
+        let answer = Answer::from(cost);
+        if !self.route {
+            return Ok(answer);
+        }
+        Ok(answer.with_details(self.draw_route(&path)))
+    }
+
+    /// The heat-loss map with the route drawn over it.
+    fn draw_route(&self, path: &[Cart]) -> String {
+        let mut drawing: Matrix<char> = self
+            .map
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|x| char::from_digit(*x, 10).unwrap())
+                    .collect()
+            })
+            .collect();
+        for cart in path {
+            if let Some(heading) = cart.heading {
+                drawing.set(cart.pos[0], cart.pos[1], heading.arrow());
+            }
+        }
+        drawing.to_string().trim_end().to_string()
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = City;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rows: Vec<Vec<u32>> = solution::lines(input)
+            .iter()
+            .map(|a| {
+                a.chars()
+                    .map(|c| {
+                        c.to_digit(10)
+                            .ok_or_else(|| Error::Input(format!("Invalid heat loss {c:?}")))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+        let map = Matrix::try_new(rows)
+            .map_err(|y| Error::Input(format!("Line {} isn't as long as the first", y + 1)))?;
+        if map.num_rows() == 0 || map.num_cols() == 0 {
+            return Err(Error::Input("Empty map".to_string()));
+        }
+
+        Ok(City {
+            map,
+            min: None,
+            max: None,
+            route: false,
+        })
+    }
+
+    /// `min` and `max` override the straight run limits, `route` draws the
+    /// path taken.
+    fn configure(city: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["min", "max", "route"])?;
+        city.min = params.get("min")?;
+        city.max = params.get("max")?;
+        city.route = params.get("route")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(city: &Self::Input) -> Result<Answer> {
+        city.least_heat_loss(CRUCIBLE)
+    }
+
+    fn part2(city: &Self::Input) -> Result<Answer> {
+        city.least_heat_loss(ULTRA_CRUCIBLE)
+    }
+}
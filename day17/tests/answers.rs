@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day17::Day17>(env!("CARGO_MANIFEST_DIR"));
+}
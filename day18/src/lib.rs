@@ -0,0 +1,135 @@
+pub mod polygon;
+
+use solution::{Answer, Error, Params, Result, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    direction: char,
+    distance: u32,
+}
+
+impl Step {
+    /// Reads the instruction as written: direction, distance and a colour that is ignored.
+    fn plain(s: &str) -> Option<Self> {
+        let mut parts = s.split(' ');
+        let direction = parts.next()?.chars().next()?;
+        if !"RDLU".contains(direction) {
+            return None;
+        }
+        let distance = parts.next()?.parse::<u32>().ok()?;
+
+        Some(Step {
+            direction,
+            distance,
+        })
+    }
+
+    /// Reads the instruction hidden in the colour `(#rrrrrd)`: 5 hex digits of distance then a direction digit.
+    fn hex(s: &str) -> Option<Self> {
+        let hex = s
+            .split(' ')
+            .next_back()?
+            .strip_prefix("(#")?
+            .strip_suffix(')')?;
+        if hex.len() != 6 || !hex.chars().all(|x| x.is_ascii_hexdigit()) {
+            return None;
+        }
+        let distance = u32::from_str_radix(&hex[..5], 16).ok()?;
+        let direction = match hex[5..].parse::<u32>().ok()? {
+            0 => 'R',
+            1 => 'D',
+            2 => 'L',
+            3 => 'U',
+            _ => return None,
+        };
+
+        Some(Step {
+            direction,
+            distance,
+        })
+    }
+
+    fn delta(&self) -> [i64; 2] {
+        match self.direction {
+            'R' => [1, 0],
+            'L' => [-1, 0],
+            'U' => [0, -1],
+            _ => [0, 1],
+        }
+    }
+}
+
+/// Which way to read the dig instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    Plain,
+    Hex,
+}
+
+impl FromStr for Reading {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "plain" => Ok(Reading::Plain),
+            "hex" => Ok(Reading::Hex),
+            _ => Err(Error::Param(format!(
+                "Unknown reading {s:?}, expected plain or hex"
+            ))),
+        }
+    }
+}
+
+pub struct DigPlan {
+    plain: Vec<Step>,
+    hex: Vec<Step>,
+    reading: Option<Reading>,
+}
+
+impl DigPlan {
+    fn lagoon_size(&self, default: Reading) -> Result<Answer> {
+        let steps = match self.reading.unwrap_or(default) {
+            Reading::Plain => &self.plain,
+            Reading::Hex => &self.hex,
+        };
+        polygon::lagoon_size(steps)
+            .map(Answer::from)
+            .ok_or_else(|| Error::Input("The trench doesn't loop back to the start".to_string()))
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut plan = DigPlan {
+            plain: Vec::new(),
+            hex: Vec::new(),
+            reading: None,
+        };
+        for line in solution::lines(input) {
+            let invalid = || Error::Input(format!("Invalid dig instruction {line:?}"));
+            plan.plain.push(Step::plain(&line).ok_or_else(invalid)?);
+            plan.hex.push(Step::hex(&line).ok_or_else(invalid)?);
+        }
+        Ok(plan)
+    }
+
+    /// `reading` makes both parts read the instructions as `plain` or `hex`.
+    fn configure(plan: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["reading"])?;
+        plan.reading = params.get("reading")?;
+        Ok(())
+    }
+
+    fn part1(plan: &Self::Input) -> Result<Answer> {
+        plan.lagoon_size(Reading::Plain)
+    }
+
+    fn part2(plan: &Self::Input) -> Result<Answer> {
+        plan.lagoon_size(Reading::Hex)
+    }
+}
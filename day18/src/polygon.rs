@@ -0,0 +1,37 @@
+use crate::Step;
+
+/// Corners of the trench dug by following `steps` from the origin.
+pub fn vertices(steps: &[Step]) -> Vec<[i64; 2]> {
+    let mut ret = vec![[0, 0]];
+    let mut pos = [0, 0];
+    for step in steps {
+        let [dx, dy] = step.delta();
+        let distance = i64::from(step.distance);
+        pos = [pos[0] + dx * distance, pos[1] + dy * distance];
+        ret.push(pos);
+    }
+    ret
+}
+
+/// Twice the area enclosed by `vertices`, by the shoelace formula.
+fn double_area(vertices: &[[i64; 2]]) -> i64 {
+    vertices
+        .windows(2)
+        .map(|w| w[0][0] * w[1][1] - w[1][0] * w[0][1])
+        .sum::<i64>()
+        .abs()
+}
+
+/// Cubic meters dug out, trench included, if `steps` lead back to the start.
+///
+/// The trench's centers enclose an area A with B points on its boundary, one
+/// per meter of trench. By Pick's theorem it holds I = A - B/2 + 1 points
+/// inside, so the lagoon is I + B = A + B/2 + 1 cubes.
+pub fn lagoon_size(steps: &[Step]) -> Option<i64> {
+    let vertices = vertices(steps);
+    if vertices.last() != Some(&[0, 0]) {
+        return None;
+    }
+    let boundary: i64 = steps.iter().map(|x| i64::from(x.distance)).sum();
+    Some((double_area(&vertices) + boundary) / 2 + 1)
+}
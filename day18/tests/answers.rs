@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day18::Day18>(env!("CARGO_MANIFEST_DIR"));
+}
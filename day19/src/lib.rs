@@ -0,0 +1,357 @@
+mod tree;
+
+use solution::{Answer, Error, Params, Result, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+pub use tree::Tree;
+
+/// One of the four ratings of a part.
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    const ALL: [Category; 4] = [Category::X, Category::M, Category::A, Category::S];
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'x' => Some(Category::X),
+            'm' => Some(Category::M),
+            'a' => Some(Category::A),
+            's' => Some(Category::S),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Category::X => "x",
+            Category::M => "m",
+            Category::A => "a",
+            Category::S => "s",
+        }
+    }
+
+    fn idx(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+struct Part {
+    ratings: [i64; 4],
+}
+
+#[derive(Debug)]
+struct PartErr;
+impl FromStr for Part {
+    type Err = PartErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut ratings = [None; 4];
+        for rating in s
+            .strip_prefix('{')
+            .and_then(|x| x.strip_suffix('}'))
+            .ok_or(PartErr)?
+            .split(',')
+        {
+            let (name, val) = rating.split_once('=').ok_or(PartErr)?;
+            let mut name = name.chars();
+            let category = name.next().and_then(Category::from_char).ok_or(PartErr)?;
+            if name.next().is_some() {
+                return Err(PartErr);
+            }
+            ratings[category.idx()] = Some(val.parse::<i64>().map_err(|_| PartErr)?);
+        }
+        let [Some(x), Some(m), Some(a), Some(s)] = ratings else {
+            return Err(PartErr);
+        };
+        Ok(Part {
+            ratings: [x, m, a, s],
+        })
+    }
+}
+
+impl Part {
+    fn sum(&self) -> i64 {
+        self.ratings.iter().sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    Greater,
+}
+
+/// A comparison of one rating against a value, like `a<2006`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Condition {
+    category: Category,
+    cmp: Comparison,
+    val: i64,
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cmp = match self.cmp {
+            Comparison::Less => '<',
+            Comparison::Greater => '>',
+        };
+        write!(f, "{}{cmp}{}", self.category.name(), self.val)
+    }
+}
+
+impl Condition {
+    fn matches(&self, p: &Part) -> bool {
+        let val = p.ratings[self.category.idx()];
+        match self.cmp {
+            Comparison::Less => val < self.val,
+            Comparison::Greater => val > self.val,
+        }
+    }
+
+    /// Splits `range` into the part that matches and the part that doesn't.
+    fn split(&self, range: Range) -> (Range, Range) {
+        let (mut acc, mut rej) = (range, range);
+        let idx = self.category.idx();
+        match self.cmp {
+            Comparison::Less => {
+                acc.bounds[idx][1] = acc.bounds[idx][1].min(self.val - 1);
+                rej.bounds[idx][0] = rej.bounds[idx][0].max(self.val);
+            }
+            Comparison::Greater => {
+                acc.bounds[idx][0] = acc.bounds[idx][0].max(self.val + 1);
+                rej.bounds[idx][1] = rej.bounds[idx][1].min(self.val);
+            }
+        }
+        (acc, rej)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    condition: Option<Condition>,
+    next: String,
+}
+
+#[derive(Debug)]
+struct RuleErr;
+impl FromStr for Rule {
+    type Err = RuleErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((rule, next)) = s.split_once(':') else {
+            return Ok(Rule {
+                condition: None,
+                next: s.to_string(),
+            });
+        };
+        let mut chars = rule.chars();
+        let category = chars.next().and_then(Category::from_char).ok_or(RuleErr)?;
+        let cmp = match chars.next() {
+            Some('<') => Comparison::Less,
+            Some('>') => Comparison::Greater,
+            _ => return Err(RuleErr),
+        };
+        Ok(Rule {
+            condition: Some(Condition {
+                category,
+                cmp,
+                val: chars.as_str().parse().map_err(|_| RuleErr)?,
+            }),
+            next: next.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.condition {
+            Some(condition) => write!(f, "{condition}:{}", self.next),
+            None => write!(f, "{}", self.next),
+        }
+    }
+}
+
+/// Every part whose ratings are all within their inclusive bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    bounds: [[i64; 2]; 4],
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self {
+            bounds: [[1, 4000]; 4],
+        }
+    }
+}
+
+impl Range {
+    /// How many parts are within the range, or `None` if that doesn't fit
+    /// in a `u128`.
+    fn combinations(&self) -> Option<u128> {
+        self.bounds.iter().try_fold(1u128, |acc, [start, end]| {
+            acc.checked_mul((*end as i128 - *start as i128 + 1).max(0) as u128)
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bounds.iter().any(|[start, end]| start > end)
+    }
+}
+
+/// Inclusive bounds of one rating, written `start..=end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds([i64; 2]);
+
+impl FromStr for Bounds {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (start, end) = s.split_once("..=").ok_or(())?;
+        Ok(Bounds([
+            start.parse().map_err(|_| ())?,
+            end.parse().map_err(|_| ())?,
+        ]))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Workflow {
+    id: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct WorkflowErr;
+impl FromStr for Workflow {
+    type Err = WorkflowErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (id, rules) = s.split_once('{').ok_or(WorkflowErr)?;
+        let rules: Vec<Rule> = rules
+            .strip_suffix('}')
+            .ok_or(WorkflowErr)?
+            .split(',')
+            .map(|x| Rule::from_str(x).map_err(|_| WorkflowErr))
+            .collect::<std::result::Result<_, _>>()?;
+        // Every part has to go somewhere
+        if rules.last().is_none_or(|x| x.condition.is_some()) {
+            return Err(WorkflowErr);
+        }
+        Ok(Workflow {
+            id: id.to_string(),
+            rules,
+        })
+    }
+}
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+    tree: Tree,
+    range: Range,
+    unreachable: bool,
+    dot: bool,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let contents = solution::lines(input);
+
+        let workflows = contents
+            .iter()
+            .take_while(|x| !x.is_empty())
+            .map(|x| {
+                Workflow::from_str(x)
+                    .map(|w| (w.id.clone(), w))
+                    .map_err(|_| Error::Input(format!("Invalid workflow {x:?}")))
+            })
+            .collect::<Result<HashMap<String, Workflow>>>()?;
+
+        let parts = contents
+            .iter()
+            .skip_while(|x| !x.is_empty())
+            .skip(1)
+            .map(|x| Part::from_str(x).map_err(|_| Error::Input(format!("Invalid part {x:?}"))))
+            .collect::<Result<Vec<Part>>>()?;
+
+        Ok(System {
+            tree: Tree::compile(&workflows)?,
+            workflows,
+            parts,
+            range: Range::default(),
+            unreachable: false,
+            dot: false,
+        })
+    }
+
+    /// `x`, `m`, `a` and `s` bound the ratings counted by part2, as in
+    /// `x=1..=100`. `unreachable` lists the rules no part within those bounds
+    /// gets sent on by, and `dot` draws the compiled decision tree.
+    fn configure(system: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["x", "m", "a", "s", "unreachable", "dot"])?;
+        for category in Category::ALL {
+            if let Some(Bounds(bounds)) = params.get(category.name())? {
+                system.range.bounds[category.idx()] = bounds;
+            }
+        }
+        system.unreachable = params.get("unreachable")?.unwrap_or(false);
+        system.dot = params.get("dot")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(system: &Self::Input) -> Result<Answer> {
+        Ok(system
+            .parts
+            .iter()
+            .filter(|part| system.tree.accepts(part))
+            .map(|part| part.sum())
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(system: &Self::Input) -> Result<Answer> {
+        let (accepted, applied) = system.tree.count(system.range)?;
+
+        let mut details = Vec::new();
+        if system.unreachable {
+            let mut unreachable: Vec<String> = system
+                .workflows
+                .values()
+                .flat_map(|w| {
+                    w.rules
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| !applied.contains(&(w.id.clone(), *i)))
+                        .map(|(i, rule)| format!("{} rule {} ({rule}) never applies", w.id, i + 1))
+                })
+                .collect();
+            unreachable.sort();
+            if unreachable.is_empty() {
+                unreachable.push("Every rule applies to some part".to_string());
+            }
+            details.extend(unreachable);
+        }
+        if system.dot {
+            details.push(system.tree.to_dot());
+        }
+
+        let answer = Answer::from(accepted);
+        if details.is_empty() {
+            return Ok(answer);
+        }
+        Ok(answer.with_details(details.join("\n")))
+    }
+}
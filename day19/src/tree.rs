@@ -0,0 +1,213 @@
+use crate::{Condition, Part, Range, Workflow};
+use solution::{Error, Result};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Which workflow a rule is in, and where in it.
+type RuleId = (String, usize);
+
+const ACCEPT: usize = 0;
+const REJECT: usize = 1;
+
+#[derive(Debug, Clone)]
+enum Node {
+    Accept,
+    Reject,
+    /// Parts matching `condition` go on to `then`, the others to `otherwise`.
+    Test {
+        rule: RuleId,
+        condition: Condition,
+        then: usize,
+        otherwise: usize,
+    },
+    /// A rule without a condition, sending every part on to `next`.
+    Jump {
+        rule: RuleId,
+        next: usize,
+    },
+}
+
+/// The workflows compiled into a single decision tree, starting at `in`.
+/// Nodes reached from several places are shared.
+#[derive(Debug, Clone)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+struct Compiler<'a> {
+    workflows: &'a HashMap<String, Workflow>,
+    nodes: Vec<Node>,
+    compiled: HashMap<RuleId, usize>,
+    /// Workflows being compiled, to notice cycles.
+    stack: Vec<String>,
+}
+
+impl Compiler<'_> {
+    /// The node for the decisions made by `workflow` from its rule `index` on.
+    fn rule(&mut self, workflow: &str, index: usize) -> Result<usize> {
+        let id = (workflow.to_string(), index);
+        if let Some(node) = self.compiled.get(&id) {
+            return Ok(*node);
+        }
+
+        let w = self
+            .workflows
+            .get(workflow)
+            .ok_or_else(|| Error::Input(format!("Unknown workflow {workflow:?}")))?;
+        if index == 0 {
+            if let Some(start) = self.stack.iter().position(|x| x == workflow) {
+                let mut cycle = self.stack[start..].to_vec();
+                cycle.push(workflow.to_string());
+                return Err(Error::Input(format!(
+                    "Workflows loop: {}",
+                    cycle.join(" -> ")
+                )));
+            }
+            self.stack.push(workflow.to_string());
+        }
+
+        let rule = &w.rules[index];
+        let next = self.target(&rule.next)?;
+        let node = match rule.condition {
+            Some(condition) => Node::Test {
+                rule: id.clone(),
+                condition,
+                then: next,
+                otherwise: self.rule(workflow, index + 1)?,
+            },
+            None => Node::Jump {
+                rule: id.clone(),
+                next,
+            },
+        };
+
+        if index == 0 {
+            self.stack.pop();
+        }
+        self.nodes.push(node);
+        self.compiled.insert(id, self.nodes.len() - 1);
+        Ok(self.nodes.len() - 1)
+    }
+
+    fn target(&mut self, name: &str) -> Result<usize> {
+        match name {
+            "A" => Ok(ACCEPT),
+            "R" => Ok(REJECT),
+            _ => self.rule(name, 0),
+        }
+    }
+}
+
+impl Tree {
+    /// Fails if a workflow sends parts to one that doesn't exist, or if
+    /// workflows can send parts around in a loop.
+    pub(crate) fn compile(workflows: &HashMap<String, Workflow>) -> Result<Self> {
+        let mut compiler = Compiler {
+            workflows,
+            nodes: vec![Node::Accept, Node::Reject],
+            compiled: HashMap::new(),
+            stack: Vec::new(),
+        };
+        let root = compiler.target("in")?;
+        Ok(Tree {
+            nodes: compiler.nodes,
+            root,
+        })
+    }
+
+    pub(crate) fn accepts(&self, part: &Part) -> bool {
+        let mut node = self.root;
+        loop {
+            node = match &self.nodes[node] {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Test {
+                    condition,
+                    then,
+                    otherwise,
+                    ..
+                } => {
+                    if condition.matches(part) {
+                        *then
+                    } else {
+                        *otherwise
+                    }
+                }
+                Node::Jump { next, .. } => *next,
+            }
+        }
+    }
+
+    /// How many parts within `range` get accepted, and which rules sent some
+    /// of them on. Fails if there are too many to count.
+    pub fn count(&self, range: Range) -> Result<(u128, HashSet<RuleId>)> {
+        let too_many = || Error::Param("Too many combinations to count".to_string());
+        let mut applied = HashSet::new();
+        let mut accepted: u128 = 0;
+        let mut stack = vec![(self.root, range)];
+        while let Some((node, range)) = stack.pop() {
+            if range.is_empty() {
+                continue;
+            }
+            match &self.nodes[node] {
+                Node::Accept => {
+                    accepted = range
+                        .combinations()
+                        .and_then(|x| accepted.checked_add(x))
+                        .ok_or_else(too_many)?;
+                }
+                Node::Reject => (),
+                Node::Test {
+                    rule,
+                    condition,
+                    then,
+                    otherwise,
+                } => {
+                    let (matching, rest) = condition.split(range);
+                    if !matching.is_empty() {
+                        applied.insert(rule.clone());
+                    }
+                    stack.push((*then, matching));
+                    stack.push((*otherwise, rest));
+                }
+                Node::Jump { rule, next } => {
+                    applied.insert(rule.clone());
+                    stack.push((*next, range));
+                }
+            }
+        }
+        Ok((accepted, applied))
+    }
+
+    /// The tree in Graphviz's DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut ret = "digraph workflows {\n".to_string();
+        for (i, node) in self.nodes.iter().enumerate() {
+            match node {
+                Node::Accept => writeln!(ret, "    n{i} [shape=box, label=\"A\"];"),
+                Node::Reject => writeln!(ret, "    n{i} [shape=box, label=\"R\"];"),
+                Node::Test {
+                    rule,
+                    condition,
+                    then,
+                    otherwise,
+                } => writeln!(
+                    ret,
+                    "    n{i} [shape=diamond, label=\"{}: {condition}\"];\n    \
+                     n{i} -> n{then} [label=\"yes\"];\n    \
+                     n{i} -> n{otherwise} [label=\"no\"];",
+                    rule.0
+                ),
+                Node::Jump { rule, next } => writeln!(
+                    ret,
+                    "    n{i} [shape=ellipse, label=\"{}\"];\n    n{i} -> n{next};",
+                    rule.0
+                ),
+            }
+            .unwrap();
+        }
+        ret += "}";
+        ret
+    }
+}
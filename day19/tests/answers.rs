@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day19::Day19>(env!("CARGO_MANIFEST_DIR"));
+}
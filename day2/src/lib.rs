@@ -0,0 +1,239 @@
+use solution::{Answer, Error, Params, Result, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Colour {
+    const ALL: [Colour; 3] = [Colour::Red, Colour::Green, Colour::Blue];
+
+    fn name(self) -> &'static str {
+        match self {
+            Colour::Red => "red",
+            Colour::Green => "green",
+            Colour::Blue => "blue",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Colour::ALL.into_iter().find(|x| x.name() == name)
+    }
+
+    fn idx(self) -> usize {
+        self as usize
+    }
+}
+
+/// How many cubes of each colour, like `3 blue, 4 red`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CubeSet {
+    counts: [usize; 3],
+}
+
+impl CubeSet {
+    pub fn get(&self, colour: Colour) -> usize {
+        self.counts[colour.idx()]
+    }
+
+    /// Whether every cube of `other` could have come out of this set.
+    pub fn contains(&self, other: &CubeSet) -> bool {
+        self.counts.iter().zip(other.counts).all(|(x, y)| *x >= y)
+    }
+
+    /// The smallest set containing both.
+    pub fn union(&self, other: &CubeSet) -> CubeSet {
+        CubeSet {
+            counts: Colour::ALL.map(|c| self.get(c).max(other.get(c))),
+        }
+    }
+
+    pub fn power(&self) -> usize {
+        self.counts.iter().product()
+    }
+}
+
+impl FromStr for CubeSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut counts = [None; 3];
+        for cubes in s.split(',') {
+            let (count, name) = cubes
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| format!("Expected a count and a colour in {cubes:?}"))?;
+            let count = count
+                .parse()
+                .map_err(|_| format!("Invalid count {count:?}"))?;
+            let colour =
+                Colour::from_name(name).ok_or_else(|| format!("Unknown colour {name:?}"))?;
+            if counts[colour.idx()].replace(count).is_some() {
+                return Err(format!("Colour {name} given twice in {:?}", s.trim()));
+            }
+        }
+        Ok(CubeSet {
+            counts: counts.map(|x| x.unwrap_or(0)),
+        })
+    }
+}
+
+impl fmt::Display for CubeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = Colour::ALL
+            .map(|c| format!("{} {}", self.get(c), c.name()))
+            .join(", ");
+        write!(f, "{cubes}")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: usize,
+    sets: Vec<CubeSet>,
+}
+
+impl Game {
+    /// The smallest bag every set shown could have come from.
+    pub fn minimum_bag(&self) -> CubeSet {
+        self.sets
+            .iter()
+            .fold(CubeSet::default(), |bag, set| bag.union(set))
+    }
+
+    pub fn is_possible(&self, bag: &CubeSet) -> bool {
+        bag.contains(&self.minimum_bag())
+    }
+}
+
+impl FromStr for Game {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (game, sets) = s
+            .split_once(':')
+            .ok_or_else(|| "Expected \"Game <id>:\"".to_string())?;
+        let id = game
+            .strip_prefix("Game ")
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| format!("Invalid game {game:?}"))?;
+        Ok(Game {
+            id,
+            sets: sets
+                .split(';')
+                .map(CubeSet::from_str)
+                .collect::<std::result::Result<_, _>>()?,
+        })
+    }
+}
+
+/// Figures about one colour across every game.
+struct Stats {
+    colour: Colour,
+    /// Cubes shown in every set of every game.
+    shown: usize,
+    /// Most cubes shown at once, and in which game.
+    most: Option<(usize, usize)>,
+}
+
+impl Stats {
+    fn new(games: &[Game], colour: Colour) -> Self {
+        let shown = games
+            .iter()
+            .flat_map(|g| g.sets.iter())
+            .map(|s| s.get(colour))
+            .sum();
+        let most = games
+            .iter()
+            .map(|g| (g.minimum_bag().get(colour), g.id))
+            .max_by_key(|(count, id)| (*count, std::cmp::Reverse(*id)));
+        Stats {
+            colour,
+            shown,
+            most,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} shown", self.colour.name(), self.shown)?;
+        if let Some((count, id)) = self.most {
+            write!(f, ", at most {count} at once in game {id}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Record {
+    games: Vec<Game>,
+    bag: CubeSet,
+    stats: bool,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Record;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let games = solution::lines(input)
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                Game::from_str(line).map_err(|e| Error::Input(format!("Line {}: {e}", i + 1)))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Record {
+            games,
+            bag: CubeSet {
+                counts: [12, 13, 14],
+            },
+            stats: false,
+        })
+    }
+
+    /// `bag` sets the cubes part1 checks the games against, as in
+    /// `12 red, 13 green, 14 blue`, and `stats` has it show figures about
+    /// each colour.
+    fn configure(record: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["bag", "stats"])?;
+        if let Some(bag) = params.get("bag")? {
+            record.bag = bag;
+        }
+        record.stats = params.get("stats")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(record: &Self::Input) -> Result<Answer> {
+        let sum = record
+            .games
+            .iter()
+            .filter(|x| x.is_possible(&record.bag))
+            .map(|x| x.id)
+            .sum::<usize>();
+
+        let answer = Answer::from(sum);
+        if !record.stats {
+            return Ok(answer);
+        }
+        let stats = Colour::ALL
+            .map(|c| Stats::new(&record.games, c).to_string())
+            .join("\n");
+        Ok(answer.with_details(stats))
+    }
+
+    fn part2(record: &Self::Input) -> Result<Answer> {
+        Ok(record
+            .games
+            .iter()
+            .map(|x| x.minimum_bag().power())
+            .sum::<usize>()
+            .into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day2::Day2>(env!("CARGO_MANIFEST_DIR"));
+}
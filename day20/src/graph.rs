@@ -0,0 +1,155 @@
+use crate::{ModuleType, Network};
+use solution::{Error, Result};
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Dot,
+    Mermaid,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "dot" => Ok(Format::Dot),
+            "mermaid" => Ok(Format::Mermaid),
+            _ => Err(Error::Param(format!(
+                "Unknown graph format {s:?}, expected dot or mermaid"
+            ))),
+        }
+    }
+}
+
+/// How to draw the network, and where to.
+#[derive(Debug, Clone)]
+pub struct Export {
+    pub format: Format,
+    /// Written to instead of being shown with the answer.
+    pub path: Option<PathBuf>,
+    /// Labels every module with the pulses it received after this many
+    /// presses.
+    pub presses: Option<u64>,
+}
+
+enum Kind {
+    FlipFlop,
+    Conjunction,
+    Broadcast,
+    /// Names modules send to without being modules themselves.
+    Sink,
+}
+
+impl Kind {
+    fn of(module: Option<&ModuleType>) -> Self {
+        match module {
+            Some(ModuleType::FlipFlop(_)) => Kind::FlipFlop,
+            Some(ModuleType::Conjunction(_)) => Kind::Conjunction,
+            Some(ModuleType::Broadcast(_)) => Kind::Broadcast,
+            None => Kind::Sink,
+        }
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        match self {
+            Kind::FlipFlop => "box",
+            Kind::Conjunction => "invtriangle",
+            Kind::Broadcast => "doubleoctagon",
+            Kind::Sink => "plaintext",
+        }
+    }
+
+    fn mermaid_brackets(&self) -> (&'static str, &'static str) {
+        match self {
+            Kind::FlipFlop => ("[", "]"),
+            Kind::Conjunction => ("((", "))"),
+            Kind::Broadcast => ("{{", "}}"),
+            Kind::Sink => (">", "]"),
+        }
+    }
+}
+
+impl Network {
+    /// Every module and sink, sorted by name, with the pulses each received.
+    fn nodes(&self) -> Vec<(String, Kind, [i64; 2])> {
+        let mut names: Vec<&String> = self
+            .modules
+            .keys()
+            .chain(self.modules.values().flat_map(|x| x.get_downstreams()))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .map(|name| {
+                let module = self.modules.get(name);
+                let pulses = match module {
+                    Some(module) => module.pulses(),
+                    None => self.sinks.get(name).copied().unwrap_or_default(),
+                };
+                (name.clone(), Kind::of(module), pulses)
+            })
+            .collect()
+    }
+
+    /// Every connection, sorted.
+    fn edges(&self) -> Vec<(&str, &str)> {
+        let mut edges: Vec<(&str, &str)> = self
+            .modules
+            .iter()
+            .flat_map(|(name, module)| {
+                module
+                    .get_downstreams()
+                    .iter()
+                    .map(move |to| (name.as_str(), to.as_str()))
+            })
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    /// The network as a Graphviz or Mermaid graph, after pressing the button
+    /// `presses` times if the pulses should be shown.
+    pub fn export(&self, format: Format, presses: Option<u64>) -> String {
+        let mut network = self.clone();
+        for _ in 0..presses.unwrap_or(0) {
+            network.press(|_| ());
+        }
+        let label = |name: &str, pulses: [i64; 2]| match presses {
+            Some(_) => format!("{name}\\n{} low, {} high", pulses[0], pulses[1]),
+            None => name.to_string(),
+        };
+
+        let mut ret = String::new();
+        match format {
+            Format::Dot => {
+                ret += "digraph modules {\n";
+                for (name, kind, pulses) in network.nodes() {
+                    let label = label(&name, pulses);
+                    let shape = kind.dot_shape();
+                    writeln!(ret, "    {name} [shape={shape}, label=\"{label}\"];").unwrap();
+                }
+                for (from, to) in network.edges() {
+                    writeln!(ret, "    {from} -> {to};").unwrap();
+                }
+                ret += "}";
+            }
+            Format::Mermaid => {
+                ret += "flowchart LR\n";
+                for (name, kind, pulses) in network.nodes() {
+                    let label = label(&name, pulses).replace("\\n", "<br>");
+                    let (open, close) = kind.mermaid_brackets();
+                    writeln!(ret, "    {name}{open}\"{label}\"{close}").unwrap();
+                }
+                for (from, to) in network.edges() {
+                    writeln!(ret, "    {from} --> {to}").unwrap();
+                }
+                ret.pop();
+            }
+        }
+        ret
+    }
+}
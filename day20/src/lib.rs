@@ -0,0 +1,501 @@
+mod graph;
+mod trace;
+
+pub use graph::{Export, Format};
+use solution::{Answer, Error, Params, Result, Solution};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+pub use trace::{Breakpoint, Trace};
+
+#[derive(Debug, Clone)]
+struct Signal {
+    from: String,
+    to: String,
+    pulse: bool,
+}
+
+impl Signal {
+    fn new(from: &str, to: &str, pulse: bool) -> Self {
+        Signal {
+            from: from.to_string(),
+            to: to.to_string(),
+            pulse,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ModuleType {
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+    Broadcast(Broadcast),
+}
+
+#[derive(Debug)]
+struct ModduleTypeErr;
+impl FromStr for ModuleType {
+    type Err = ModduleTypeErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (module, connections) = s.split_once(" -> ").ok_or(ModduleTypeErr)?;
+        let connections: Vec<String> = connections.split(", ").map(|x| x.to_string()).collect();
+        match module.chars().next().ok_or(ModduleTypeErr)? {
+            '%' => Ok(ModuleType::FlipFlop(FlipFlop::new(
+                module.chars().skip(1).collect::<String>(),
+                connections,
+            ))),
+            '&' => Ok(ModuleType::Conjunction(Conjunction::new(
+                module.chars().skip(1).collect::<String>(),
+                connections,
+            ))),
+            _ => Ok(ModuleType::Broadcast(Broadcast::new(
+                module.to_string(),
+                connections,
+            ))),
+        }
+    }
+}
+
+impl ModuleType {
+    fn get_name(&self) -> String {
+        match self {
+            ModuleType::FlipFlop(f) => f.name.clone(),
+            ModuleType::Conjunction(c) => c.name.clone(),
+            ModuleType::Broadcast(b) => b.name.clone(),
+        }
+    }
+
+    fn add_upstream(&mut self, name: String) {
+        match self {
+            ModuleType::FlipFlop(f) => f.upstream.push(name),
+            ModuleType::Conjunction(c) => {
+                c.upstream.push(name);
+                c.last.push(false)
+            }
+            ModuleType::Broadcast(b) => b.upstream.push(name),
+        }
+    }
+
+    fn get_downstreams(&self) -> &[String] {
+        match self {
+            ModuleType::FlipFlop(f) => &f.downstream,
+            ModuleType::Conjunction(c) => &c.downstream,
+            ModuleType::Broadcast(b) => &b.downstream,
+        }
+    }
+
+    fn run(&mut self, signal: &Signal) -> Vec<Signal> {
+        match self {
+            ModuleType::FlipFlop(f) => f.run(signal),
+            ModuleType::Conjunction(c) => c.run(signal),
+            ModuleType::Broadcast(b) => b.run(signal),
+        }
+    }
+
+    fn pulses(&self) -> [i64; 2] {
+        match self {
+            ModuleType::FlipFlop(f) => f.pulses,
+            ModuleType::Conjunction(c) => c.pulses,
+            ModuleType::Broadcast(b) => b.pulses,
+        }
+    }
+}
+
+trait ModuleRun {
+    fn run(&mut self, signal: &Signal) -> Vec<Signal>;
+}
+
+#[derive(Clone, Debug)]
+struct FlipFlop {
+    name: String,
+    upstream: Vec<String>,
+    downstream: Vec<String>,
+    pulses: [i64; 2],
+    state: bool,
+}
+
+impl FlipFlop {
+    fn new(name: String, downstream: Vec<String>) -> Self {
+        FlipFlop {
+            name,
+            upstream: Vec::new(),
+            downstream,
+            pulses: [0, 0],
+            state: false,
+        }
+    }
+}
+
+impl ModuleRun for FlipFlop {
+    fn run(&mut self, signal: &Signal) -> Vec<Signal> {
+        if !signal.pulse {
+            self.pulses[0] += 1;
+            self.state = !self.state;
+            self.downstream
+                .iter()
+                .map(|x| Signal::new(&self.name, x, self.state))
+                .collect()
+        } else {
+            self.pulses[1] += 1;
+            vec![]
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Conjunction {
+    name: String,
+    upstream: Vec<String>,
+    downstream: Vec<String>,
+    pulses: [i64; 2],
+    last: Vec<bool>,
+}
+
+impl Conjunction {
+    fn new(name: String, downstream: Vec<String>) -> Self {
+        Conjunction {
+            name,
+            upstream: Vec::new(),
+            downstream,
+            pulses: [0, 0],
+            last: Vec::new(),
+        }
+    }
+}
+
+impl ModuleRun for Conjunction {
+    fn run(&mut self, signal: &Signal) -> Vec<Signal> {
+        if signal.pulse {
+            self.pulses[1] += 1;
+        } else {
+            self.pulses[0] += 1;
+        }
+
+        let idx = self
+            .upstream
+            .iter()
+            .position(|x| x == &signal.from)
+            .unwrap();
+        self.last[idx] = signal.pulse;
+        if self.last.iter().all(|x| *x) {
+            self.downstream
+                .iter()
+                .map(|x| Signal::new(&self.name, x, false))
+                .collect()
+        } else {
+            self.downstream
+                .iter()
+                .map(|x| Signal::new(&self.name, x, true))
+                .collect()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Broadcast {
+    name: String,
+    upstream: Vec<String>,
+    downstream: Vec<String>,
+    pulses: [i64; 2],
+}
+
+impl Broadcast {
+    fn new(name: String, downstream: Vec<String>) -> Self {
+        Broadcast {
+            name,
+            upstream: Vec::new(),
+            downstream,
+            pulses: [0, 0],
+        }
+    }
+}
+
+impl ModuleRun for Broadcast {
+    fn run(&mut self, signal: &Signal) -> Vec<Signal> {
+        if signal.pulse {
+            self.pulses[1] += 1;
+        } else {
+            self.pulses[0] += 1;
+        }
+
+        self.downstream
+            .iter()
+            .map(|x| Signal::new(&self.name, x, signal.pulse))
+            .collect()
+    }
+}
+
+/// Gives up looking for a period after this many presses.
+const MAX_PRESSES: u64 = 100_000;
+
+/// Most presses traced looking for a breakpoint, unless `trace` says otherwise.
+const BREAK_PRESSES: u64 = 1000;
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Network {
+    modules: HashMap<String, ModuleType>,
+    /// Pulses received by names that aren't modules, like `output` or `rx`.
+    sinks: HashMap<String, [i64; 2]>,
+    export: Option<Export>,
+    trace: Option<Trace>,
+}
+
+impl Network {
+    /// Pushes the button once and runs the network until it settles,
+    /// calling `on_signal` for every signal delivered.
+    fn press(&mut self, mut on_signal: impl FnMut(&Signal)) {
+        self.press_while(|signal| {
+            on_signal(signal);
+            true
+        });
+    }
+
+    /// Like `press`, but stops as soon as `on_signal` returns false, right
+    /// after delivering that signal. Returns whether the network settled.
+    fn press_while(&mut self, mut on_signal: impl FnMut(&Signal) -> bool) -> bool {
+        let mut next_signals: Vec<Signal> = vec![Signal::new("button", "broadcaster", false)];
+        while !next_signals.is_empty() {
+            let mut collected_signals = Vec::new();
+            for signal in next_signals {
+                if let Some(module) = self.modules.get_mut(&signal.to) {
+                    collected_signals.append(&mut module.run(&signal));
+                } else {
+                    self.sinks.entry(signal.to.clone()).or_default()[usize::from(signal.pulse)] +=
+                        1;
+                }
+                if !on_signal(&signal) {
+                    return false;
+                }
+            }
+            next_signals = collected_signals;
+        }
+        true
+    }
+
+    fn pulses(&self) -> [i64; 2] {
+        self.modules
+            .values()
+            .map(|x| x.pulses())
+            .chain(self.sinks.values().cloned())
+            .fold([0, 0], |acc, x| [acc[0] + x[0], acc[1] + x[1]])
+    }
+
+    /// Presses needed for `rx` to get a low pulse. In the puzzle's networks a
+    /// single conjunction feeds `rx`, and each of its inputs sends it a high
+    /// pulse every so many presses, so `rx` gets a low pulse once all of those
+    /// periods line up.
+    fn presses_until_rx(&self) -> Result<u64> {
+        let feeders: Vec<&ModuleType> = self
+            .modules
+            .values()
+            .filter(|x| x.get_downstreams().iter().any(|d| d == "rx"))
+            .collect();
+        let feeder = match feeders.as_slice() {
+            [] => return Err(Error::Input("No module sends to rx".to_string())),
+            [ModuleType::Conjunction(c)] => c,
+            _ => {
+                return Err(Error::Input(
+                    "rx should be fed by a single conjunction".to_string(),
+                ))
+            }
+        };
+
+        // Presses on which each input of the feeder sent it a high pulse
+        let mut highs: HashMap<String, Vec<u64>> = feeder
+            .upstream
+            .iter()
+            .map(|x| (x.clone(), Vec::new()))
+            .collect();
+        let mut network = self.clone();
+        let mut presses = 0;
+        while highs.values().any(|x| x.len() < 2) {
+            if presses == MAX_PRESSES {
+                return Err(Error::Input(format!(
+                    "Some input of {} didn't go high twice in {MAX_PRESSES} presses",
+                    feeder.name
+                )));
+            }
+            presses += 1;
+            network.press(|signal| {
+                if signal.to == feeder.name && signal.pulse {
+                    let seen = highs.get_mut(&signal.from).unwrap();
+                    if seen.last() != Some(&presses) {
+                        seen.push(presses);
+                    }
+                }
+            });
+        }
+
+        let mut ret = 1;
+        for (name, seen) in highs.iter() {
+            let period = seen[0];
+            if seen[1] != 2 * period {
+                return Err(Error::Input(format!(
+                    "{name} goes high on presses {} and {}, not periodically",
+                    seen[0], seen[1]
+                )));
+            }
+            ret = ret / gcd(ret, period) * period;
+        }
+        Ok(ret)
+    }
+}
+
+/// `contents` to show with the answer, or what to say about writing them to
+/// `path` instead.
+fn show(what: &str, contents: String, path: Option<&Path>) -> Result<String> {
+    let Some(path) = path else {
+        return Ok(contents);
+    };
+    fs::write(path, contents + "\n")
+        .map_err(|e| Error::Param(format!("Unable to write {}: {e}", path.display())))?;
+    Ok(format!("Wrote the {what} to {}", path.display()))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut modules = solution::lines(input)
+            .iter()
+            .map(|x| {
+                ModuleType::from_str(x)
+                    .map(|m| (m.get_name(), m))
+                    .map_err(|_| Error::Input(format!("Invalid module {x:?}")))
+            })
+            .collect::<Result<HashMap<String, ModuleType>>>()?;
+        for module in modules.clone().values() {
+            for name in module.get_downstreams() {
+                if let Some(downstream) = modules.get_mut(name) {
+                    downstream.add_upstream(module.get_name());
+                }
+            }
+        }
+        if !modules.contains_key("broadcaster") {
+            return Err(Error::Input("No broadcaster module".to_string()));
+        }
+
+        Ok(Network {
+            modules,
+            sinks: HashMap::new(),
+            export: None,
+            trace: None,
+        })
+    }
+
+    /// `graph` draws the network as `dot` or `mermaid` along with part1,
+    /// written to `path` if given. `presses` labels every module with the
+    /// pulses it received after that many presses.
+    ///
+    /// `trace` records the signals of that many presses as JSON lines, written
+    /// to `log` if given, with the state of the modules after each press if
+    /// `memory` is set. `break` stops once a module receives a pulse, as in
+    /// `kz:high`, within the first 1000 presses unless `trace` says otherwise.
+    fn configure(network: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&[
+            "graph", "path", "presses", "trace", "memory", "break", "log",
+        ])?;
+        let path = params.get("path")?;
+        let presses = params.get("presses")?;
+        network.export = match params.get::<Format>("graph")? {
+            Some(format) => Some(Export {
+                format,
+                path,
+                presses,
+            }),
+            None if path.is_some() || presses.is_some() => {
+                return Err(Error::Param("path and presses need a graph".to_string()))
+            }
+            None => None,
+        };
+
+        let memory = params.get("memory")?.unwrap_or(false);
+        let breakpoint: Option<Breakpoint> = params.get("break")?;
+        if let Some(b) = &breakpoint {
+            let known = network.modules.contains_key(&b.module)
+                || network
+                    .modules
+                    .values()
+                    .any(|m| m.get_downstreams().contains(&b.module));
+            if !known {
+                return Err(Error::Param(format!(
+                    "No module {:?} to break on",
+                    b.module
+                )));
+            }
+        }
+        let log = params.get("log")?;
+        let presses = match params.get("trace")? {
+            Some(presses) => presses,
+            None if breakpoint.is_some() => BREAK_PRESSES,
+            None if memory || log.is_some() => {
+                return Err(Error::Param(
+                    "memory and log need a trace or a break".to_string(),
+                ))
+            }
+            None => return Ok(()),
+        };
+        network.trace = Some(Trace {
+            presses,
+            memory,
+            breakpoint,
+            path: log,
+        });
+        Ok(())
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer> {
+        let mut pressed = network.clone();
+        for _ in 0..1000 {
+            pressed.press(|_| ());
+        }
+        let pulses = pressed.pulses();
+        let answer = Answer::from(pulses[0] * pulses[1]);
+
+        let mut details = Vec::new();
+        if let Some(export) = &network.export {
+            let graph = network.export(export.format, export.presses);
+            details.push(show("graph", graph, export.path.as_deref())?);
+        }
+        if let Some(trace) = &network.trace {
+            details.push(match &trace.path {
+                Some(path) => {
+                    let unable =
+                        |e| Error::Param(format!("Unable to write {}: {e}", path.display()));
+                    let mut log = BufWriter::new(fs::File::create(path).map_err(unable)?);
+                    network
+                        .trace(trace, &mut log)
+                        .and_then(|_| log.flush())
+                        .map_err(unable)?;
+                    format!("Wrote the trace to {}", path.display())
+                }
+                None => {
+                    let mut lines = Vec::new();
+                    network.trace(trace, &mut lines).unwrap();
+                    String::from_utf8(lines).unwrap().trim_end().to_string()
+                }
+            });
+        }
+        if details.is_empty() {
+            return Ok(answer);
+        }
+        Ok(answer.with_details(details.join("\n")))
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer> {
+        Ok(network.presses_until_rx()?.into())
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{ModuleType, Network};
+use serde_json::{json, Map, Value};
+use solution::{Error, Result};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn pulse_name(pulse: bool) -> &'static str {
+    if pulse {
+        "high"
+    } else {
+        "low"
+    }
+}
+
+/// Stops the trace once `module` receives a `pulse`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoint {
+    pub module: String,
+    pub pulse: bool,
+}
+
+impl FromStr for Breakpoint {
+    type Err = Error;
+
+    /// `module:high`, `module:low`, or just `module` to stop on a high pulse.
+    fn from_str(s: &str) -> Result<Self> {
+        let (module, pulse) = s.split_once(':').unwrap_or((s, "high"));
+        let pulse = match pulse {
+            "high" => true,
+            "low" => false,
+            _ => return Err(Error::Param(format!("Invalid pulse {pulse:?}"))),
+        };
+        Ok(Breakpoint {
+            module: module.to_string(),
+            pulse,
+        })
+    }
+}
+
+/// What to record while pressing the button.
+#[derive(Debug, Clone)]
+pub struct Trace {
+    /// Most presses to record.
+    pub presses: u64,
+    /// Also record the state of every module after each press.
+    pub memory: bool,
+    pub breakpoint: Option<Breakpoint>,
+    /// Written to instead of being shown with the answer.
+    pub path: Option<PathBuf>,
+}
+
+impl Network {
+    /// State of every flip-flop and the last pulse every conjunction got from
+    /// each of its inputs.
+    fn memory(&self) -> Value {
+        let mut ret = Map::new();
+        for (name, module) in self.modules.iter() {
+            let state = match module {
+                ModuleType::FlipFlop(f) => json!(f.state),
+                ModuleType::Conjunction(c) => c
+                    .upstream
+                    .iter()
+                    .zip(c.last.iter())
+                    .map(|(from, pulse)| (from.clone(), json!(pulse_name(*pulse))))
+                    .collect::<Map<_, _>>()
+                    .into(),
+                ModuleType::Broadcast(_) => continue,
+            };
+            ret.insert(name.clone(), state);
+        }
+        ret.into()
+    }
+
+    /// Presses the button as `trace` says, writing one JSON object per line
+    /// to `out` for every signal delivered, for the memory after each press
+    /// if asked to, and for the breakpoint if it was hit.
+    pub fn trace(&self, trace: &Trace, out: &mut impl Write) -> io::Result<()> {
+        let mut network = self.clone();
+        for press in 1..=trace.presses {
+            let mut order = 0;
+            let mut hit = None;
+            let mut written = Ok(());
+            let settled = network.press_while(|signal| {
+                let line = json!({
+                    "press": press,
+                    "order": order,
+                    "from": signal.from,
+                    "to": signal.to,
+                    "pulse": pulse_name(signal.pulse),
+                });
+                written = writeln!(out, "{line}");
+                order += 1;
+                hit = trace
+                    .breakpoint
+                    .as_ref()
+                    .filter(|b| b.module == signal.to && b.pulse == signal.pulse);
+                written.is_ok() && hit.is_none()
+            });
+            written?;
+            if trace.memory {
+                writeln!(
+                    out,
+                    "{}",
+                    json!({"press": press, "memory": network.memory()})
+                )?;
+            }
+            if let (false, Some(b)) = (settled, hit) {
+                let line = json!({
+                    "press": press,
+                    "break": format!("{} receives {}", b.module, pulse_name(b.pulse)),
+                });
+                writeln!(out, "{line}")?;
+                break;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day20::Day20>(env!("CARGO_MANIFEST_DIR"));
+}
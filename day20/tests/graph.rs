@@ -0,0 +1,88 @@
+use day20::{Day20, Format};
+use solution::Solution;
+
+fn network() -> day20::Network {
+    Day20::parse(include_str!("../inputs/example2")).unwrap()
+}
+
+#[test]
+fn exports_dot() {
+    assert_eq!(
+        network().export(Format::Dot, None),
+        r#"digraph modules {
+    a [shape=box, label="a"];
+    b [shape=box, label="b"];
+    broadcaster [shape=doubleoctagon, label="broadcaster"];
+    con [shape=invtriangle, label="con"];
+    inv [shape=invtriangle, label="inv"];
+    output [shape=plaintext, label="output"];
+    a -> con;
+    a -> inv;
+    b -> con;
+    broadcaster -> a;
+    con -> output;
+    inv -> b;
+}"#
+    );
+}
+
+#[test]
+fn exports_dot_with_pulses() {
+    assert_eq!(
+        network().export(Format::Dot, Some(1)),
+        r#"digraph modules {
+    a [shape=box, label="a\n1 low, 0 high"];
+    b [shape=box, label="b\n1 low, 0 high"];
+    broadcaster [shape=doubleoctagon, label="broadcaster\n1 low, 0 high"];
+    con [shape=invtriangle, label="con\n0 low, 2 high"];
+    inv [shape=invtriangle, label="inv\n0 low, 1 high"];
+    output [shape=plaintext, label="output\n1 low, 1 high"];
+    a -> con;
+    a -> inv;
+    b -> con;
+    broadcaster -> a;
+    con -> output;
+    inv -> b;
+}"#
+    );
+}
+
+#[test]
+fn exports_mermaid() {
+    assert_eq!(
+        network().export(Format::Mermaid, None),
+        r#"flowchart LR
+    a["a"]
+    b["b"]
+    broadcaster{{"broadcaster"}}
+    con(("con"))
+    inv(("inv"))
+    output>"output"]
+    a --> con
+    a --> inv
+    b --> con
+    broadcaster --> a
+    con --> output
+    inv --> b"#
+    );
+}
+
+#[test]
+fn exports_mermaid_with_pulses() {
+    assert_eq!(
+        network().export(Format::Mermaid, Some(1)),
+        r#"flowchart LR
+    a["a<br>1 low, 0 high"]
+    b["b<br>1 low, 0 high"]
+    broadcaster{{"broadcaster<br>1 low, 0 high"}}
+    con(("con<br>0 low, 2 high"))
+    inv(("inv<br>0 low, 1 high"))
+    output>"output<br>1 low, 1 high"]
+    a --> con
+    a --> inv
+    b --> con
+    broadcaster --> a
+    con --> output
+    inv --> b"#
+    );
+}
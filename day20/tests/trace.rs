@@ -0,0 +1,84 @@
+use day20::{Breakpoint, Day20, Trace};
+use serde_json::{json, Value};
+use solution::Solution;
+
+fn trace(presses: u64, memory: bool, breakpoint: Option<&str>) -> Vec<Value> {
+    let network = Day20::parse(include_str!("../inputs/example2")).unwrap();
+    let trace = Trace {
+        presses,
+        memory,
+        breakpoint: breakpoint.map(|x| x.parse::<Breakpoint>().unwrap()),
+        path: None,
+    };
+    let mut out = Vec::new();
+    network.trace(&trace, &mut out).unwrap();
+    String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(|x| serde_json::from_str(x).unwrap())
+        .collect()
+}
+
+fn signal(order: u64, from: &str, to: &str, pulse: &str) -> Value {
+    json!({"press": 1, "order": order, "from": from, "to": to, "pulse": pulse})
+}
+
+#[test]
+fn traces_signals_and_memory() {
+    assert_eq!(
+        trace(1, true, None),
+        [
+            signal(0, "button", "broadcaster", "low"),
+            signal(1, "broadcaster", "a", "low"),
+            signal(2, "a", "inv", "high"),
+            signal(3, "a", "con", "high"),
+            signal(4, "inv", "b", "low"),
+            signal(5, "con", "output", "high"),
+            signal(6, "b", "con", "high"),
+            signal(7, "con", "output", "low"),
+            json!({
+                "press": 1,
+                "memory": {
+                    "a": true,
+                    "b": true,
+                    "con": {"a": "high", "b": "high"},
+                    "inv": {"a": "high"},
+                },
+            }),
+        ]
+    );
+}
+
+#[test]
+fn numbers_every_press() {
+    let lines = trace(4, false, None);
+    assert_eq!(lines.len(), 8 + 6 + 8 + 6);
+    assert_eq!(
+        lines[8],
+        json!({"press": 2, "order": 0, "from": "button", "to": "broadcaster", "pulse": "low"})
+    );
+    assert_eq!(lines.last().unwrap()["press"], 4);
+}
+
+#[test]
+fn stops_at_the_breakpoint() {
+    assert_eq!(
+        trace(3, true, Some("con:high")),
+        [
+            signal(0, "button", "broadcaster", "low"),
+            signal(1, "broadcaster", "a", "low"),
+            signal(2, "a", "inv", "high"),
+            signal(3, "a", "con", "high"),
+            json!({
+                "press": 1,
+                "memory": {
+                    "a": true,
+                    "b": false,
+                    "con": {"a": "high", "b": "low"},
+                    "inv": {"a": "high"},
+                },
+            }),
+            json!({"press": 1, "break": "con receives high"}),
+        ]
+    );
+}
@@ -0,0 +1,211 @@
+use matrix::{Matrix, TiledView};
+use search::Grid;
+use solution::{Answer, Error, Params, Result, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Path {
+    len: usize,
+    head: [i64; 2],
+}
+
+impl Path {
+    fn new(head: [i64; 2]) -> Self {
+        Path { len: 0, head }
+    }
+
+    fn next_steps(&self, garden: &TiledView<char>) -> Vec<Path> {
+        garden
+            .neighbors(self.head[0], self.head[1])
+            .filter(|x| *x.1 != '#')
+            .map(|x| {
+                let mut new = *self;
+                new.head = x.0;
+                new.len += 1;
+                new
+            })
+            .collect()
+    }
+}
+
+pub struct Garden {
+    plots: Matrix<char>,
+    start: [usize; 2],
+    steps: Option<usize>,
+    check: bool,
+}
+
+/// Walks the infinitely repeated garden step by step, only usable for small step counts.
+pub fn reachable_tiled(garden: &Garden, steps: usize) -> usize {
+    let tiled = garden.plots.tiled();
+    let mut heads = HashSet::from([Path::new([
+        i64::try_from(garden.start[0]).unwrap(),
+        i64::try_from(garden.start[1]).unwrap(),
+    ])]);
+    for _ in 0..steps {
+        let mut new_heads = HashSet::new();
+        for head in heads.iter() {
+            new_heads.extend(head.next_steps(&tiled));
+        }
+        heads = new_heads;
+    }
+
+    heads.len()
+}
+
+/// Breadth-first walk over the infinitely repeated garden, counting the plots
+/// first reached after each number of steps. Only the last two layers are
+/// kept, as the neighbors of a plot are at most one step closer than it.
+struct Walk<'a> {
+    tiled: TiledView<'a, char>,
+    previous: HashSet<[i64; 2]>,
+    current: HashSet<[i64; 2]>,
+    layers: Vec<usize>,
+}
+
+impl<'a> Walk<'a> {
+    fn new(garden: &'a Garden) -> Self {
+        let start = [
+            i64::try_from(garden.start[0]).unwrap(),
+            i64::try_from(garden.start[1]).unwrap(),
+        ];
+        Walk {
+            tiled: garden.plots.tiled(),
+            previous: HashSet::new(),
+            current: HashSet::from([start]),
+            layers: vec![1],
+        }
+    }
+
+    fn step(&mut self) {
+        let next: HashSet<[i64; 2]> = self
+            .current
+            .iter()
+            .flat_map(|pos| self.tiled.neighbors(pos[0], pos[1]))
+            .filter(|x| *x.1 != '#')
+            .map(|x| x.0)
+            .filter(|pos| !self.previous.contains(pos) && !self.current.contains(pos))
+            .collect();
+        self.layers.push(next.len());
+        self.previous = std::mem::replace(&mut self.current, next);
+    }
+
+    /// Plots reachable in exactly `steps` steps.
+    fn reachable(&mut self, steps: usize) -> usize {
+        while self.layers.len() <= steps {
+            self.step();
+        }
+        // A plot reached in d steps can be reached again in d + 2 by stepping back and forth
+        self.layers[steps % 2..=steps].iter().step_by(2).sum()
+    }
+}
+
+/// Consecutive fits of a quadratic needed to trust it.
+const SETTLED: usize = 2;
+/// Most periods walked looking for a quadratic.
+const MAX_PERIODS: usize = 16;
+
+/// Plots reachable in `steps` steps in the infinitely repeated garden. Far
+/// enough out the walk crosses a whole tile every period, so the counts at
+/// `offset + k * period` steps grow quadratically with `k`. Those counts are
+/// walked until they fit a quadratic, which then gives the count for `steps`.
+/// With an odd tile size, every other period might step onto the other color
+/// of the checkerboard, so twice the tile size is tried too.
+pub fn reachable_infinite(garden: &Garden, steps: usize) -> Result<u128> {
+    let size = garden.plots.num_rows();
+    if garden.plots.num_cols() != size {
+        return Err(Error::Input("The garden should be square".to_string()));
+    }
+
+    let mut walk = Walk::new(garden);
+    for period in [size, 2 * size] {
+        let offset = steps % period;
+        let mut counts: Vec<i128> = Vec::new();
+        let mut settled = 0;
+        for k in 0..MAX_PERIODS {
+            let walked = offset + k * period;
+            if walked >= steps {
+                return Ok(u128::try_from(walk.reachable(steps)).unwrap());
+            }
+            counts.push(i128::try_from(walk.reachable(walked)).unwrap());
+
+            let [a, b, c, d] = match counts.len().checked_sub(4) {
+                Some(i) => [counts[i], counts[i + 1], counts[i + 2], counts[i + 3]],
+                None => continue,
+            };
+            if d - 3 * c + 3 * b - a != 0 {
+                settled = 0;
+                continue;
+            }
+            settled += 1;
+            if settled == SETTLED {
+                let t = i128::try_from((steps - walked) / period).unwrap() + 2;
+                let count = b + t * (c - b) + t * (t - 1) / 2 * (d - 2 * c + b);
+                return u128::try_from(count)
+                    .map_err(|_| Error::Input("The count went negative".to_string()));
+            }
+        }
+    }
+    Err(Error::Input(
+        "The reachable plots don't grow quadratically".to_string(),
+    ))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let plots = Matrix::try_new(solution::lines(input).iter().map(|x| x.chars().collect()))
+            .map_err(|y| Error::Input(format!("Line {} isn't as long as the first", y + 1)))?;
+        let start = plots
+            .find('S')
+            .ok_or_else(|| Error::Input("No starting point".to_string()))?;
+        Ok(Garden {
+            plots,
+            start,
+            steps: None,
+            check: false,
+        })
+    }
+
+    /// `steps` overrides the number of steps of either part, `check` walks
+    /// part2 step by step too, which only works for small step counts.
+    fn configure(garden: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["steps", "check"])?;
+        garden.steps = params.get("steps")?;
+        garden.check = params.get("check")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(garden: &Self::Input) -> Result<Answer> {
+        let grid = Grid::new(&garden.plots).passable(|c| *c != '#');
+        let distances = search::bfs(grid.cell(garden.start)).distances;
+
+        // A plot reached in d steps can be reached again in d + 2 by stepping back and forth
+        let steps = garden.steps.unwrap_or(64);
+        let reachable = distances
+            .values()
+            .filter(|d| **d <= steps && **d % 2 == steps % 2)
+            .count();
+
+        Ok(reachable.into())
+    }
+
+    fn part2(garden: &Self::Input) -> Result<Answer> {
+        let steps = garden.steps.unwrap_or(26501365);
+        let count = reachable_infinite(garden, steps)?;
+        if !garden.check {
+            return Ok(count.into());
+        }
+
+        let walked = u128::try_from(reachable_tiled(garden, steps)).unwrap();
+        if walked != count {
+            return Err(Error::Input(format!(
+                "Walking step by step reaches {walked} plots, not {count}"
+            )));
+        }
+        Ok(Answer::from(count).with_details("Walking step by step agrees"))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day21::Day21>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,154 @@
+use solution::{Answer, Error, Result, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Brick {
+    start: [i32; 3],
+    end: [i32; 3],
+}
+
+#[derive(Debug)]
+pub struct BrickParseError;
+impl FromStr for Brick {
+    type Err = BrickParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (start, end) = s.split_once('~').ok_or(BrickParseError)?;
+        let parse = |coords: &str| -> std::result::Result<[i32; 3], BrickParseError> {
+            coords
+                .split(',')
+                .map(|x| x.parse::<i32>().map_err(|_| BrickParseError))
+                .collect::<std::result::Result<Vec<i32>, _>>()?
+                .try_into()
+                .map_err(|_| BrickParseError)
+        };
+        let (start, end) = (parse(start)?, parse(end)?);
+        if (0..3).any(|i| start[i] > end[i]) || start[2] < 1 {
+            return Err(BrickParseError);
+        }
+        Ok(Brick { start, end })
+    }
+}
+
+impl Brick {
+    /// The columns the brick takes up, seen from above.
+    fn footprint(&self) -> impl Iterator<Item = [i32; 2]> + '_ {
+        (self.start[0]..=self.end[0])
+            .flat_map(move |x| (self.start[1]..=self.end[1]).map(move |y| [x, y]))
+    }
+
+    fn drop_to(&mut self, z: i32) {
+        self.end[2] -= self.start[2] - z;
+        self.start[2] = z;
+    }
+}
+
+/// The bricks once they've all fallen, and which rest on which.
+pub struct Stack {
+    bricks: Vec<Brick>,
+    /// Bricks each brick rests on, none for those on the ground.
+    below: Vec<Vec<usize>>,
+    /// Bricks resting on each brick.
+    above: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Drops the bricks lowest first, keeping the top-most brick of every
+    /// column in a height map, so each brick lands on the highest of the
+    /// columns under it.
+    fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(|x| x.start[2]);
+        let mut heights: HashMap<[i32; 2], (i32, usize)> = HashMap::new();
+        let mut below = Vec::with_capacity(bricks.len());
+        let mut above = vec![Vec::new(); bricks.len()];
+        for (idx, brick) in bricks.iter_mut().enumerate() {
+            let under: Vec<(i32, usize)> = brick
+                .footprint()
+                .filter_map(|xy| heights.get(&xy).copied())
+                .collect();
+            let rest = under.iter().map(|x| x.0).max().unwrap_or(0);
+            let mut supports: Vec<usize> =
+                under.iter().filter(|x| x.0 == rest).map(|x| x.1).collect();
+            supports.sort();
+            supports.dedup();
+            for support in supports.iter() {
+                above[*support].push(idx);
+            }
+            below.push(supports);
+
+            brick.drop_to(rest + 1);
+            for xy in brick.footprint() {
+                heights.insert(xy, (brick.end[2], idx));
+            }
+        }
+        Stack {
+            bricks,
+            below,
+            above,
+        }
+    }
+
+    /// How many other bricks fall when each brick is taken out.
+    ///
+    /// A brick falls when every path of supports from it down to the ground
+    /// goes through the brick taken out: the bricks that fall are the ones
+    /// it dominates, with the ground as the root. Supports always come before
+    /// the bricks they hold up, so the immediate dominator of a brick is the
+    /// nearest common dominator of its supports, and the dominator tree can
+    /// be built in one pass.
+    fn chain_reactions(&self) -> Vec<usize> {
+        let ground = self.bricks.len();
+        let mut idom = vec![ground; ground + 1];
+        let mut depth = vec![0; ground + 1];
+        for (idx, supports) in self.below.iter().enumerate() {
+            let mut dominator = supports.first().copied().unwrap_or(ground);
+            for &support in supports.iter().skip(1) {
+                let mut other = support;
+                while dominator != other {
+                    if depth[dominator] < depth[other] {
+                        std::mem::swap(&mut dominator, &mut other);
+                    }
+                    dominator = idom[dominator];
+                }
+            }
+            idom[idx] = dominator;
+            depth[idx] = depth[dominator] + 1;
+        }
+
+        // Bricks only dominate bricks settled after them
+        let mut dominated = vec![0; ground + 1];
+        for idx in (0..ground).rev() {
+            dominated[idom[idx]] += dominated[idx] + 1;
+        }
+        dominated.truncate(ground);
+        dominated
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Stack;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let bricks = solution::lines(input)
+            .iter()
+            .map(|x| Brick::from_str(x).map_err(|_| Error::Input(format!("Invalid brick {x:?}"))))
+            .collect::<Result<Vec<Brick>>>()?;
+        Ok(Stack::settle(bricks))
+    }
+
+    fn part1(stack: &Self::Input) -> Result<Answer> {
+        let deletable = stack
+            .above
+            .iter()
+            .filter(|above| above.iter().all(|x| stack.below[*x].len() > 1))
+            .count();
+        Ok(deletable.into())
+    }
+
+    fn part2(stack: &Self::Input) -> Result<Answer> {
+        Ok(stack.chain_reactions().iter().sum::<usize>().into())
+    }
+}
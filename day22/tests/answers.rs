@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day22::Day22>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,226 @@
+use matrix::Matrix;
+use solution::{Answer, Error, Params, Result, Solution};
+
+/// A corridor from one junction to another.
+#[derive(Debug, Clone)]
+struct Edge {
+    to: usize,
+    len: usize,
+    /// Cells walked through, ending at the junction reached.
+    cells: Vec<[usize; 2]>,
+}
+
+/// The trails compressed to the cells where they fork, plus the start and
+/// the end, with the corridors in between.
+#[derive(Debug, Clone)]
+struct Graph {
+    junctions: Vec<[usize; 2]>,
+    edges: Vec<Vec<Edge>>,
+}
+
+/// Whether stepping from `from` onto `to` is allowed by the slope there.
+fn downhill(map: &Matrix<char>, from: [usize; 2], to: [usize; 2]) -> bool {
+    match map[to] {
+        '^' => to[1] + 1 == from[1],
+        '>' => to[0] == from[0] + 1,
+        '<' => to[0] + 1 == from[0],
+        'v' => to[1] == from[1] + 1,
+        _ => true,
+    }
+}
+
+fn open(map: &Matrix<char>, pos: [usize; 2]) -> Vec<[usize; 2]> {
+    map.get_neighbors(pos[0], pos[1])
+        .into_iter()
+        .filter(|x| *x.1 != '#')
+        .map(|x| x.0)
+        .collect()
+}
+
+impl Graph {
+    fn new(map: &Matrix<char>, start: [usize; 2], end: [usize; 2], slopes: bool) -> Self {
+        let mut junctions = vec![start, end];
+        for y in 0..map.num_rows() {
+            for x in 0..map.num_cols() {
+                if map[[x, y]] != '#' && open(map, [x, y]).len() > 2 {
+                    junctions.push([x, y]);
+                }
+            }
+        }
+
+        let edges = junctions
+            .iter()
+            .map(|&junction| {
+                open(map, junction)
+                    .into_iter()
+                    .filter_map(|first| {
+                        let mut cells = vec![first];
+                        let mut prev = junction;
+                        loop {
+                            let cur = *cells.last().unwrap();
+                            if slopes && !downhill(map, prev, cur) {
+                                return None;
+                            }
+                            if let Some(to) = junctions.iter().position(|x| *x == cur) {
+                                let len = cells.len();
+                                return Some(Edge { to, len, cells });
+                            }
+                            // Dead ends have a single way out, the one we came from
+                            let next = open(map, cur).into_iter().find(|x| *x != prev)?;
+                            prev = cur;
+                            cells.push(next);
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Graph { junctions, edges }
+    }
+
+    /// The longest route from the start to the end going through no
+    /// junction twice, as its length and the junctions visited.
+    fn longest_route(&self) -> Result<Option<(usize, Vec<usize>)>> {
+        if self.junctions.len() > 128 {
+            return Err(Error::Input(format!(
+                "{} junctions are too many to search",
+                self.junctions.len()
+            )));
+        }
+
+        // The end has a single way in: once there, going anywhere else would
+        // cut it off
+        let last = self
+            .edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edges)| edges.iter().any(|e| e.to == 1))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let last = match last[..] {
+            [last] => Some(last),
+            _ => None,
+        };
+
+        let mut best = None;
+        let mut route = vec![0];
+        self.search(0, 1, 0, last, &mut route, &mut best);
+        Ok(best)
+    }
+
+    fn search(
+        &self,
+        node: usize,
+        visited: u128,
+        len: usize,
+        last: Option<usize>,
+        route: &mut Vec<usize>,
+        best: &mut Option<(usize, Vec<usize>)>,
+    ) {
+        if node == 1 {
+            if best.as_ref().is_none_or(|b| len > b.0) {
+                *best = Some((len, route.clone()));
+            }
+            return;
+        }
+        for edge in self.edges[node].iter() {
+            if visited & (1 << edge.to) != 0 || (Some(node) == last && edge.to != 1) {
+                continue;
+            }
+            route.push(edge.to);
+            self.search(
+                edge.to,
+                visited | (1 << edge.to),
+                len + edge.len,
+                last,
+                route,
+                best,
+            );
+            route.pop();
+        }
+    }
+
+    /// Every cell walked along a route through the junctions.
+    fn cells(&self, route: &[usize]) -> Vec<[usize; 2]> {
+        let mut ret = vec![self.junctions[route[0]]];
+        for w in route.windows(2) {
+            let edge = self.edges[w[0]]
+                .iter()
+                .filter(|e| e.to == w[1])
+                .max_by_key(|e| e.len)
+                .unwrap();
+            ret.extend(edge.cells.iter().copied());
+        }
+        ret
+    }
+}
+
+pub struct Trails {
+    map: Matrix<char>,
+    start: [usize; 2],
+    end: [usize; 2],
+    route: bool,
+}
+
+impl Trails {
+    /// Length of the longest hike from the top row to the bottom row.
+    fn longest_hike(&self, slopes: bool) -> Result<Answer> {
+        let graph = Graph::new(&self.map, self.start, self.end, slopes);
+        let (len, route) = graph
+            .longest_route()?
+            .ok_or_else(|| Error::Input("No path to the bottom row".to_string()))?;
+
+        let answer = Answer::from(len);
+        if !self.route {
+            return Ok(answer);
+        }
+        let mut drawing = self.map.clone();
+        for [x, y] in graph.cells(&route) {
+            drawing.set(x, y, 'O');
+        }
+        Ok(answer.with_details(drawing.to_string().trim_end().to_string()))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Trails;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map = Matrix::try_new(solution::lines(input).iter().map(|x| x.chars().collect()))
+            .map_err(|y| Error::Input(format!("Line {} isn't as long as the first", y + 1)))?;
+        let opening = |y: usize| {
+            map.row(y)
+                .and_then(|row| row.iter().position(|c| *c == '.'))
+                .map(|x| [x, y])
+        };
+        let start = opening(0).ok_or_else(|| Error::Input("No way in".to_string()))?;
+        let end = map
+            .num_rows()
+            .checked_sub(1)
+            .and_then(opening)
+            .ok_or_else(|| Error::Input("No way out".to_string()))?;
+        Ok(Trails {
+            map,
+            start,
+            end,
+            route: false,
+        })
+    }
+
+    /// `route` draws the hike taken.
+    fn configure(trails: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["route"])?;
+        trails.route = params.get("route")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(trails: &Self::Input) -> Result<Answer> {
+        trails.longest_hike(true)
+    }
+
+    fn part2(trails: &Self::Input) -> Result<Answer> {
+        trails.longest_hike(false)
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day23::Day23>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,252 @@
+use num_bigint::BigInt;
+use num_rational::{BigRational, Ratio};
+use num_traits::{Signed, Zero};
+use solution::{Answer, Error, Params, Result, Solution};
+use std::str::FromStr;
+
+/// Exact fractions, big enough for crossings of two puzzle hailstones.
+type Rational = Ratio<i128>;
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hailstone {
+    pos: [i128; 3],
+    vel: [i128; 3],
+}
+
+#[derive(Debug)]
+pub struct HailstoneErr;
+impl FromStr for Hailstone {
+    type Err = HailstoneErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (left, right) = s.split_once(" @ ").ok_or(HailstoneErr)?;
+        let parse = |coords: &str| -> std::result::Result<[i128; 3], HailstoneErr> {
+            coords
+                .split(", ")
+                .map(|x| i128::from_str(x.trim_start()).map_err(|_| HailstoneErr))
+                .collect::<std::result::Result<Vec<i128>, _>>()?
+                .try_into()
+                .map_err(|_| HailstoneErr)
+        };
+        Ok(Hailstone {
+            pos: parse(left)?,
+            vel: parse(right)?,
+        })
+    }
+}
+
+impl Hailstone {
+    /// Times `t` and `s` at which the paths of `self` and `other` cross when
+    /// looking only at the `axes` plane.
+    fn crossing_times(&self, other: &Self, axes: [usize; 2]) -> Option<(Rational, Rational)> {
+        let [a, b] = axes;
+        let det = other.vel[a] * self.vel[b] - self.vel[a] * other.vel[b];
+        if det == 0 {
+            return None;
+        }
+        let d = sub(other.pos, self.pos);
+        let t = Rational::new(other.vel[a] * d[b] - other.vel[b] * d[a], det);
+        let s = Rational::new(self.vel[a] * d[b] - self.vel[b] * d[a], det);
+        Some((t, s))
+    }
+
+    fn at(&self, t: Rational, axis: usize) -> Rational {
+        t * self.vel[axis] + self.pos[axis]
+    }
+
+    /// Where the paths of both hailstones cross in the xy plane, if both get
+    /// there in the future.
+    pub fn intersection_xy(&self, other: &Self) -> Option<[Rational; 2]> {
+        let (t, s) = self.crossing_times(other, [0, 1])?;
+        if !t.is_positive() || !s.is_positive() {
+            return None;
+        }
+        Some([self.at(t, 0), self.at(t, 1)])
+    }
+
+    /// Where the paths of both hailstones cross, at whatever time each of them
+    /// gets there. Parallel paths don't have a single crossing.
+    pub fn intersection(&self, other: &Self) -> Option<[Rational; 3]> {
+        let (t, s) = [[0, 1], [0, 2], [1, 2]]
+            .into_iter()
+            .find_map(|axes| self.crossing_times(other, axes))?;
+        let point = [self.at(t, 0), self.at(t, 1), self.at(t, 2)];
+        (0..3)
+            .all(|axis| point[axis] == other.at(s, axis))
+            .then_some(point)
+    }
+
+    /// When both hailstones are at the same place at the same time, if ever.
+    pub fn collision(&self, other: &Self) -> Option<Rational> {
+        let mut time = None;
+        for axis in 0..3 {
+            let dv = self.vel[axis] - other.vel[axis];
+            let dp = other.pos[axis] - self.pos[axis];
+            if dv == 0 {
+                if dp != 0 {
+                    return None;
+                }
+                continue;
+            }
+            let t = Rational::new(dp, dv);
+            if time.is_some_and(|time| time != t) {
+                return None;
+            }
+            time = Some(t);
+        }
+        // Both always at the same place
+        Some(time.unwrap_or_default())
+    }
+}
+
+/// Solves the linear system given as rows of coefficients followed by the
+/// constant, by Gaussian elimination. None if it has no single solution.
+fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !rows[row][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot = rows[col].clone();
+        for (row, other) in rows.iter_mut().enumerate() {
+            if row == col || other[col].is_zero() {
+                continue;
+            }
+            let factor = &other[col] / &pivot[col];
+            for (x, p) in other.iter_mut().zip(pivot.iter()) {
+                *x -= &factor * p;
+            }
+        }
+    }
+    Some(
+        rows.iter()
+            .enumerate()
+            .map(|(i, row)| &row[n] / &row[i])
+            .collect(),
+    )
+}
+
+/// The rock thrown from `P` at `V` hits a hailstone iff `(P - p) × (V - v) = 0`.
+/// Subtracting that equation for two hailstones `i` and `j` cancels `P × V`,
+/// leaving three equations linear in `P` and `V`:
+/// `P × (vj - vi) + (pj - pi) × V = pj × vj - pi × vi`.
+fn rock_equations(a: &Hailstone, b: &Hailstone) -> Vec<Vec<BigRational>> {
+    let w = sub(b.vel, a.vel);
+    let q = sub(b.pos, a.pos);
+    let rhs = sub(cross(b.pos, b.vel), cross(a.pos, a.vel));
+    [
+        [0, w[2], -w[1], 0, -q[2], q[1], rhs[0]],
+        [-w[2], 0, w[0], q[2], 0, -q[0], rhs[1]],
+        [w[1], -w[0], 0, -q[1], q[0], 0, rhs[2]],
+    ]
+    .into_iter()
+    .map(|row| {
+        row.into_iter()
+            .map(|x| BigRational::from_integer(BigInt::from(x)))
+            .collect()
+    })
+    .collect()
+}
+
+/// The rock which, thrown at time 0, hits every hailstone.
+pub fn rock(hailstones: &[Hailstone]) -> Result<Hailstone> {
+    let rock = hailstones
+        .windows(3)
+        .find_map(|w| {
+            let mut rows = rock_equations(&w[0], &w[1]);
+            rows.extend(rock_equations(&w[0], &w[2]));
+            solve_linear(rows)
+        })
+        .ok_or_else(|| Error::Input("Not enough independent hailstones".to_string()))?;
+    let coords = rock
+        .iter()
+        .map(|x| {
+            x.is_integer()
+                .then(|| i128::try_from(x.to_integer()).ok())
+                .flatten()
+        })
+        .collect::<Option<Vec<i128>>>()
+        .ok_or_else(|| Error::Input("The rock doesn't start on whole numbers".to_string()))?;
+    let rock = Hailstone {
+        pos: [coords[0], coords[1], coords[2]],
+        vel: [coords[3], coords[4], coords[5]],
+    };
+
+    let misses = hailstones
+        .iter()
+        .any(|h| rock.collision(h).is_none_or(|t| t.is_negative()));
+    if misses {
+        return Err(Error::Input("No rock hits every hailstone".to_string()));
+    }
+    Ok(rock)
+}
+
+pub struct Hailstorm {
+    hailstones: Vec<Hailstone>,
+    min: i128,
+    max: i128,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Hailstorm;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let hailstones = solution::lines(input)
+            .iter()
+            .map(|x| {
+                Hailstone::from_str(x).map_err(|_| Error::Input(format!("Invalid hailstone {x:?}")))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Hailstorm {
+            hailstones,
+            min: 200000000000000,
+            max: 400000000000000,
+        })
+    }
+
+    /// `min` and `max` bound the test area on both axes.
+    fn configure(storm: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["min", "max"])?;
+        if let Some(min) = params.get("min")? {
+            storm.min = min;
+        }
+        if let Some(max) = params.get("max")? {
+            storm.max = max;
+        }
+        Ok(())
+    }
+
+    fn part1(storm: &Self::Input) -> Result<Answer> {
+        let area = Rational::from(storm.min)..=Rational::from(storm.max);
+        let hailstones = &storm.hailstones;
+        let mut intersects = 0;
+        for i in 0..hailstones.len() {
+            for j in i + 1..hailstones.len() {
+                if let Some([x, y]) = hailstones[i].intersection_xy(&hailstones[j]) {
+                    if area.contains(&x) && area.contains(&y) {
+                        intersects += 1;
+                    }
+                }
+            }
+        }
+        Ok(intersects.into())
+    }
+
+    fn part2(storm: &Self::Input) -> Result<Answer> {
+        let rock = rock(&storm.hailstones)?;
+        Ok(rock.pos.iter().sum::<i128>().into())
+    }
+}
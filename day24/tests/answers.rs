@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day24::Day24>(env!("CARGO_MANIFEST_DIR"));
+}
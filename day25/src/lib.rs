@@ -0,0 +1,235 @@
+use search::SearchState;
+use solution::{Answer, Error, Result, Solution};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone)]
+pub struct Connection {
+    start: String,
+    end: String,
+}
+
+impl Connection {
+    fn new(start: &str, end: &str) -> Self {
+        Connection {
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+}
+
+impl PartialEq for Connection {
+    fn eq(&self, other: &Self) -> bool {
+        (self.start == other.start && self.end == other.end)
+            || (self.start == other.end && self.end == other.start)
+    }
+}
+impl Eq for Connection {}
+
+/// The components as numbered nodes, with every wire between them.
+struct Graph {
+    names: Vec<String>,
+    wires: Vec<[usize; 2]>,
+    /// Neighbor and wire leading to it, for every node.
+    adjacent: Vec<Vec<(usize, usize)>>,
+}
+
+/// Wires whose removal splits the graph in two, with which side of the cut
+/// each node ends up on.
+struct Cut {
+    wires: Vec<[usize; 2]>,
+    side: Vec<bool>,
+}
+
+/// A node of the graph, leading to its neighbours through every wire that
+/// can still take more flow towards them.
+#[derive(Clone)]
+struct Residual<'a> {
+    graph: &'a Graph,
+    flow: &'a [i8],
+    sink: usize,
+    node: usize,
+}
+
+impl PartialEq for Residual<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+impl Eq for Residual<'_> {}
+
+impl Hash for Residual<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+    }
+}
+
+impl SearchState for Residual<'_> {
+    type Cost = usize;
+
+    fn successors(&self) -> Vec<(Self, usize)> {
+        self.graph.adjacent[self.node]
+            .iter()
+            .filter(|(_, wire)| self.graph.forward(self.node, *wire) * self.flow[*wire] != 1)
+            .map(|&(next, _)| {
+                (
+                    Residual {
+                        node: next,
+                        ..*self
+                    },
+                    1,
+                )
+            })
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.node == self.sink
+    }
+}
+
+impl Graph {
+    fn new(connections: &[Connection]) -> Self {
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut names = Vec::new();
+        let mut id = |name: &str| -> usize {
+            *ids.entry(name.to_string()).or_insert_with(|| {
+                names.push(name.to_string());
+                names.len() - 1
+            })
+        };
+        let mut wires: Vec<[usize; 2]> = Vec::new();
+        for connection in connections {
+            let wire = [id(&connection.start), id(&connection.end)];
+            if !wires.contains(&wire) && !wires.contains(&[wire[1], wire[0]]) {
+                wires.push(wire);
+            }
+        }
+
+        let mut adjacent = vec![Vec::new(); names.len()];
+        for (i, &[a, b]) in wires.iter().enumerate() {
+            adjacent[a].push((b, i));
+            adjacent[b].push((a, i));
+        }
+        Graph {
+            names,
+            wires,
+            adjacent,
+        }
+    }
+
+    /// 1 if `wire` goes from `node` to the other end, -1 if it comes to it.
+    fn forward(&self, node: usize, wire: usize) -> i8 {
+        if self.wires[wire][0] == node {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Pushes up to `limit` wire-disjoint paths from `source` to `sink`. If
+    /// fewer than `limit` fit, returns the nodes still reachable from `source`,
+    /// which are one side of a minimum cut between the two.
+    fn separate(&self, source: usize, sink: usize, limit: usize) -> Option<Vec<bool>> {
+        // Flow through every wire, positive going from its first node to its
+        // second
+        let mut flow = vec![0i8; self.wires.len()];
+        for _ in 0..limit {
+            let start = Residual {
+                graph: self,
+                flow: &flow,
+                sink,
+                node: source,
+            };
+            let result = search::bfs(start);
+            let Some(path) = result.path() else {
+                let mut reached = vec![false; self.names.len()];
+                for state in result.distances.keys() {
+                    reached[state.node] = true;
+                }
+                return Some(reached);
+            };
+
+            let path: Vec<usize> = path.iter().map(|x| x.node).collect();
+            for step in path.windows(2) {
+                let (prev, node) = (step[0], step[1]);
+                let &(_, wire) = self.adjacent[prev]
+                    .iter()
+                    .find(|(next, _)| *next == node)
+                    .unwrap();
+                flow[wire] += self.forward(prev, wire);
+            }
+        }
+        None
+    }
+
+    /// A global minimum cut: some node is on the other side from node 0, and
+    /// the smallest cut between them is the smallest cut overall.
+    fn min_cut(&self) -> Option<Cut> {
+        let mut best: Option<Cut> = None;
+        for sink in 1..self.names.len() {
+            let limit = best.as_ref().map_or(usize::MAX, |cut| cut.wires.len());
+            let Some(side) = self.separate(0, sink, limit) else {
+                continue;
+            };
+            let wires = self
+                .wires
+                .iter()
+                .filter(|[a, b]| side[*a] != side[*b])
+                .copied()
+                .collect();
+            best = Some(Cut { wires, side });
+        }
+        best
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Connection>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut ret = Vec::new();
+        for line in solution::lines(input) {
+            let (source, connections) = line
+                .split_once(": ")
+                .ok_or_else(|| Error::Input(format!("Invalid component {line:?}")))?;
+            for connection in connections.split(' ') {
+                ret.push(Connection::new(source, connection));
+            }
+        }
+        Ok(ret)
+    }
+
+    fn part1(connections: &Self::Input) -> Result<Answer> {
+        let graph = Graph::new(connections);
+        let cut = graph
+            .min_cut()
+            .ok_or_else(|| Error::Input("Need at least two components".to_string()))?;
+        if cut.wires.len() != 3 {
+            return Err(Error::Input(format!(
+                "Expected to cut 3 wires, the minimum cut is {}",
+                cut.wires.len()
+            )));
+        }
+
+        let first = cut.side.iter().filter(|x| **x).count();
+        let second = cut.side.len() - first;
+        let wires = cut
+            .wires
+            .iter()
+            .map(|[a, b]| format!("{}/{}", graph.names[*a], graph.names[*b]))
+            .collect::<Vec<_>>();
+        Ok(Answer::from(first * second).with_details(format!(
+            "Cutting {} leaves groups of {first} and {second}",
+            wires.join(", ")
+        )))
+    }
+
+    fn part2(_connections: &Self::Input) -> Result<Answer> {
+        // There's no puzzle on the last day
+        Ok("Merry Christmas".into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day25::Day25>(env!("CARGO_MANIFEST_DIR"));
+}
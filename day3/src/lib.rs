@@ -0,0 +1,240 @@
+use matrix::{Matrix, SURROUNDING};
+use solution::{Answer, Error, Params, Result, Solution};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A number written on the schematic, spanning `cols` of row `row`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    value: usize,
+    row: usize,
+    cols: Range<usize>,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// Symbols `symbol` with exactly `arity` numbers around them, like gears
+/// being `*` next to 2 numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    symbol: char,
+    arity: usize,
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Parses `<symbol>:<arity>`, as in `*:2`.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::Param(format!("Invalid rule {s:?}, expected e.g. \"*:2\""));
+        let (symbol, arity) = s.split_once(':').ok_or_else(invalid)?;
+        let mut chars = symbol.chars();
+        let (Some(symbol), None) = (chars.next(), chars.next()) else {
+            return Err(invalid());
+        };
+        if !is_symbol(symbol) {
+            return Err(invalid());
+        }
+        Ok(Rule {
+            symbol,
+            arity: arity.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+pub struct Schematic {
+    grid: Matrix<char>,
+    numbers: Vec<Number>,
+    /// Which number covers each cell, if any.
+    owners: Matrix<Option<usize>>,
+    rule: Rule,
+    report: bool,
+}
+
+impl Schematic {
+    fn new(grid: Matrix<char>) -> Result<Self> {
+        let mut numbers = Vec::new();
+        for (y, row) in grid.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let len = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+                if len == 0 {
+                    x += 1;
+                    continue;
+                }
+                let digits: String = row[x..x + len].iter().collect();
+                let value = digits
+                    .parse()
+                    .map_err(|_| Error::Input(format!("Number {digits} is too large")))?;
+                numbers.push(Number {
+                    value,
+                    row: y,
+                    cols: x..x + len,
+                });
+                x += len;
+            }
+        }
+
+        let mut owners: Matrix<Option<usize>> =
+            grid.rows().map(|row| vec![None; row.len()]).collect();
+        for (i, number) in numbers.iter().enumerate() {
+            for x in number.cols.clone() {
+                owners.set(x, number.row, Some(i));
+            }
+        }
+
+        Ok(Schematic {
+            grid,
+            numbers,
+            owners,
+            rule: Rule {
+                symbol: '*',
+                arity: 2,
+            },
+            report: false,
+        })
+    }
+
+    /// The 8 cells around `pos` that are within the schematic.
+    fn around(&self, pos: [usize; 2]) -> impl Iterator<Item = [usize; 2]> + '_ {
+        SURROUNDING.iter().filter_map(move |[dx, dy]| {
+            let x = pos[0].checked_add_signed(*dx as isize)?;
+            let y = pos[1].checked_add_signed(*dy as isize)?;
+            self.grid.get(x, y).map(|_| [x, y])
+        })
+    }
+
+    /// Numbers with a digit among the 8 cells around `pos`, in order.
+    fn numbers_around(&self, pos: [usize; 2]) -> Vec<usize> {
+        let mut ret: Vec<usize> = self
+            .around(pos)
+            .filter_map(|cell| self.owners[cell])
+            .collect();
+        ret.sort_unstable();
+        ret.dedup();
+        ret
+    }
+
+    /// Symbols touching a number, including diagonally, with where they are.
+    fn symbols_around(&self, number: &Number) -> Vec<([usize; 2], char)> {
+        let mut ret: Vec<([usize; 2], char)> = number
+            .cols
+            .clone()
+            .flat_map(|x| self.around([x, number.row]))
+            .map(|cell| (cell, self.grid[cell]))
+            .filter(|(_, c)| is_symbol(*c))
+            .collect();
+        ret.sort_unstable();
+        ret.dedup();
+        ret
+    }
+
+    /// Every `symbol` in the schematic, with where it is.
+    fn find_all(&self, symbol: char) -> impl Iterator<Item = [usize; 2]> + '_ {
+        self.grid.rows().enumerate().flat_map(move |(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(move |(_, c)| **c == symbol)
+                .map(move |(x, _)| [x, y])
+        })
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = solution::lines(input);
+        let lines: Vec<(usize, &String)> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| !x.is_empty())
+            .collect();
+        let width = lines.first().map_or(0, |(_, x)| x.chars().count());
+        if let Some((i, _)) = lines.iter().find(|(_, x)| x.chars().count() != width) {
+            return Err(Error::Input(format!(
+                "Line {} isn't {width} characters wide",
+                i + 1
+            )));
+        }
+        Schematic::new(lines.iter().map(|(_, x)| x.chars().collect()).collect())
+    }
+
+    /// `rule` picks the symbols part2 multiplies the numbers around, as
+    /// `<symbol>:<arity>` (`*:2` for gears), and `report` lists what counted.
+    fn configure(schematic: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["rule", "report"])?;
+        if let Some(rule) = params.get("rule")? {
+            schematic.rule = rule;
+        }
+        schematic.report = params.get("report")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+        let mut details = Vec::new();
+        for number in &schematic.numbers {
+            let symbols = schematic.symbols_around(number);
+            if !symbols.is_empty() {
+                sum += number.value;
+            }
+            if schematic.report {
+                let [x, y] = [number.cols.start, number.row];
+                details.push(match &symbols[..] {
+                    [] => format!("{} at {x},{y} isn't a part number", number.value),
+                    _ => format!(
+                        "{} at {x},{y} is next to {}",
+                        number.value,
+                        symbols
+                            .iter()
+                            .map(|([x, y], c)| format!("{c} at {x},{y}"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                });
+            }
+        }
+
+        let answer = Answer::from(sum);
+        if details.is_empty() {
+            return Ok(answer);
+        }
+        Ok(answer.with_details(details.join("\n")))
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<Answer> {
+        let Rule { symbol, arity } = schematic.rule;
+        let mut sum = 0;
+        let mut details = Vec::new();
+        for [x, y] in schematic.find_all(symbol) {
+            let numbers = schematic.numbers_around([x, y]);
+            if numbers.len() != arity {
+                continue;
+            }
+            let values: Vec<usize> = numbers
+                .iter()
+                .map(|i| schematic.numbers[*i].value)
+                .collect();
+            let product = values.iter().product::<usize>();
+            sum += product;
+            if schematic.report {
+                let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                details.push(format!(
+                    "{symbol} at {x},{y}: {} = {product}",
+                    values.join(" * ")
+                ));
+            }
+        }
+
+        let answer = Answer::from(sum);
+        if details.is_empty() {
+            return Ok(answer);
+        }
+        Ok(answer.with_details(details.join("\n")))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day3::Day3>(env!("CARGO_MANIFEST_DIR"));
+}
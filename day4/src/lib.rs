@@ -0,0 +1,82 @@
+use solution::{Answer, Error, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug)]
+pub struct Card {
+    id: usize,
+    overlap: Vec<usize>,
+}
+
+impl Card {
+    fn new(id: usize, winning: HashSet<usize>, mine: HashSet<usize>) -> Self {
+        let overlap = winning.intersection(&mine).cloned().collect();
+
+        Card { id, overlap }
+    }
+
+    fn instances(&self, cards: &HashMap<usize, Card>) -> usize {
+        let mut ret: usize = 1;
+        for i in 1..=self.overlap.len() {
+            if let Some(card) = cards.get(&(self.id + i)) {
+                ret += card.instances(cards);
+            }
+        }
+        ret
+    }
+}
+
+pub struct Day4;
+
+fn parse_numbers(numbers: &str) -> Result<HashSet<usize>> {
+    numbers
+        .split_whitespace()
+        .map(|x| {
+            x.parse()
+                .map_err(|_| Error::Input(format!("Invalid number {x:?}")))
+        })
+        .collect()
+}
+
+fn parse_input(lines: &[String]) -> Result<HashMap<usize, Card>> {
+    let mut ret: HashMap<usize, Card> = HashMap::new();
+    for line in lines {
+        let invalid = || Error::Input(format!("Invalid card {line:?}"));
+        let (id, num) = line.split_once(':').ok_or_else(invalid)?;
+        let (left, right) = num.split_once('|').ok_or_else(invalid)?;
+        let winning = parse_numbers(left)?;
+        let mine = parse_numbers(right)?;
+        let id = id
+            .strip_prefix("Card")
+            .and_then(|x| x.trim().parse::<usize>().ok())
+            .ok_or_else(invalid)?;
+        ret.insert(id, Card::new(id, winning, mine));
+    }
+
+    Ok(ret)
+}
+
+impl Solution for Day4 {
+    type Input = HashMap<usize, Card>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(&solution::lines(input))
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Answer> {
+        let mut total: i64 = 0;
+        for card in cards.values() {
+            if !card.overlap.is_empty() {
+                total += 2_i64.pow((card.overlap.len() - 1).try_into().unwrap());
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Answer> {
+        Ok(cards
+            .values()
+            .map(|card| card.instances(cards))
+            .sum::<usize>()
+            .into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day4::Day4>(env!("CARGO_MANIFEST_DIR"));
+}
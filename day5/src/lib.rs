@@ -0,0 +1,117 @@
+use interval::{Interval, IntervalSet, Mapping};
+use solution::{Answer, Error, Result, Solution};
+
+/// One of the almanac's maps, from one category of numbers to the next.
+struct CategoryMap {
+    from: String,
+    to: String,
+    mapping: Mapping,
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    /// Every map from seeds to locations, composed.
+    seed_to_location: Mapping,
+}
+
+pub struct Day5;
+
+fn numbers(s: &str) -> Result<Vec<i64>> {
+    s.split_whitespace()
+        .map(|x| {
+            x.parse()
+                .map_err(|_| Error::Input(format!("Invalid number {x:?}")))
+        })
+        .collect()
+}
+
+fn parse_map(header: &str, lines: &[String]) -> Result<CategoryMap> {
+    let invalid_header = || Error::Input(format!("Invalid map {header:?}"));
+    let (from, to) = header
+        .strip_suffix(" map:")
+        .and_then(|x| x.split_once("-to-"))
+        .ok_or_else(invalid_header)?;
+
+    let mut pieces = Vec::new();
+    for line in lines {
+        let [dst, src, len] = numbers(line)?[..] else {
+            return Err(Error::Input(format!("Invalid range {line:?}")));
+        };
+        pieces.push((Interval::with_len(src, len), dst - src));
+    }
+    let mapping = Mapping::from_pieces(pieces)
+        .ok_or_else(|| Error::Input(format!("Overlapping ranges in {header:?}")))?;
+
+    Ok(CategoryMap {
+        from: from.to_string(),
+        to: to.to_string(),
+        mapping,
+    })
+}
+
+/// Composes the maps leading from seeds to locations.
+fn seed_to_location(maps: &[CategoryMap]) -> Result<Mapping> {
+    let mut category = "seed";
+    let mut ret = Mapping::new();
+    while category != "location" {
+        let map = maps
+            .iter()
+            .find(|x| x.from == category)
+            .ok_or_else(|| Error::Input(format!("No map from {category}")))?;
+        ret = ret.then(&map.mapping);
+        category = &map.to;
+    }
+    Ok(ret)
+}
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = solution::lines(input);
+        let seeds = lines
+            .first()
+            .and_then(|x| x.strip_prefix("seeds: "))
+            .ok_or_else(|| Error::Input("No seeds".to_string()))?;
+        let seeds = numbers(seeds)?;
+
+        let mut maps = Vec::new();
+        for block in lines[1..].split(|x| x.is_empty()) {
+            if let Some((header, ranges)) = block.split_first() {
+                maps.push(parse_map(header, ranges)?);
+            }
+        }
+
+        Ok(Almanac {
+            seeds,
+            seed_to_location: seed_to_location(&maps)?,
+        })
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Answer> {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.seed_to_location.apply(*seed))
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| Error::Input("No seeds".to_string()))
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Answer> {
+        if almanac.seeds.len() % 2 != 0 {
+            return Err(Error::Input("Seeds should come in pairs".to_string()));
+        }
+        let seeds: IntervalSet = almanac
+            .seeds
+            .chunks(2)
+            .map(|pair| Interval::with_len(pair[0], pair[1]))
+            .collect();
+        almanac
+            .seed_to_location
+            .image(&seeds)
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| Error::Input("No seeds".to_string()))
+    }
+}
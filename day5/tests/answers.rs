@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day5::Day5>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,168 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use solution::{Answer, Error, Params, Result, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Race {
+    time: BigUint,
+    distance: BigUint,
+}
+
+impl Race {
+    fn new(time: BigUint, distance: BigUint) -> Self {
+        Self { time, distance }
+    }
+
+    /// Whether holding the button for `hold` beats the record.
+    fn wins(&self, hold: &BigUint, acceleration: &BigUint) -> bool {
+        hold <= &self.time && acceleration * hold * (&self.time - hold) > self.distance
+    }
+
+    /// Shortest and longest winning button holds.
+    ///
+    /// Holding for `h` goes `a * h * (T - h)`, which beats `D` between the
+    /// roots of `a * h^2 - a * T * h + D`, `(a * T ± sqrt(a^2 * T^2 - 4 * a * D)) / 2a`.
+    /// The integer square root puts the first guess next to the shortest
+    /// hold, and the longest is as far from `T` as the shortest is from 0.
+    fn winning(&self, acceleration: &BigUint) -> Option<(BigUint, BigUint)> {
+        if acceleration.is_zero() {
+            return None;
+        }
+        let a_t = acceleration * &self.time;
+        let four_a_d = 4u32 * acceleration * &self.distance;
+        if a_t.pow(2) <= four_a_d {
+            return None;
+        }
+        let root = (a_t.pow(2) - four_a_d).sqrt();
+
+        let half = &self.time / 2u32;
+        let mut shortest = (a_t - root) / (2u32 * acceleration);
+        while shortest > BigUint::zero() && self.wins(&(&shortest - 1u32), acceleration) {
+            shortest -= 1u32;
+        }
+        while shortest <= half && !self.wins(&shortest, acceleration) {
+            shortest += 1u32;
+        }
+        if shortest > half {
+            return None;
+        }
+        let longest = &self.time - &shortest;
+        Some((shortest, longest))
+    }
+
+    fn num_winning(&self, acceleration: &BigUint) -> BigUint {
+        match self.winning(acceleration) {
+            Some((shortest, longest)) => longest - shortest + 1u32,
+            None => BigUint::zero(),
+        }
+    }
+}
+
+pub struct Sheet {
+    races: Vec<Race>,
+    /// Speed gained per millisecond of holding the button.
+    acceleration: BigUint,
+    bounds: bool,
+}
+
+impl Sheet {
+    fn num_winning(&self, races: &[Race]) -> Answer {
+        let answer = Answer::from(
+            races
+                .iter()
+                .map(|x| x.num_winning(&self.acceleration))
+                .product::<BigUint>()
+                .to_string(),
+        );
+        if !self.bounds {
+            return answer;
+        }
+
+        let bounds = races
+            .iter()
+            .map(|race| match race.winning(&self.acceleration) {
+                Some((shortest, longest)) => {
+                    format!("{}ms: hold {shortest}..={longest}", race.time)
+                }
+                None => format!("{}ms: can't win", race.time),
+            })
+            .collect::<Vec<_>>();
+        answer.with_details(bounds.join("\n"))
+    }
+}
+
+pub struct Day6;
+
+fn numbers(line: Option<&String>, name: &str) -> Result<Vec<BigUint>> {
+    let values = line
+        .and_then(|x| x.strip_prefix(name))
+        .and_then(|x| x.strip_prefix(':'))
+        .ok_or_else(|| Error::Input(format!("No {name} line")))?;
+    values
+        .split_whitespace()
+        .map(|x| {
+            x.parse()
+                .map_err(|_| Error::Input(format!("Invalid number {x:?}")))
+        })
+        .collect()
+}
+
+/// Reads the numbers of a sheet as one number, ignoring the kerning.
+fn unkerned<'a>(values: impl Iterator<Item = &'a BigUint>) -> BigUint {
+    values
+        .map(|x| x.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+impl Solution for Day6 {
+    type Input = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = solution::lines(input);
+        let times = numbers(lines.first(), "Time")?;
+        let distances = numbers(lines.get(1), "Distance")?;
+        if times.len() != distances.len() {
+            return Err(Error::Input(
+                "Times and distance aren't the same size".to_string(),
+            ));
+        }
+        if times.is_empty() {
+            return Err(Error::Input("No races".to_string()));
+        }
+
+        Ok(Sheet {
+            races: times
+                .into_iter()
+                .zip(distances)
+                .map(|(time, distance)| Race::new(time, distance))
+                .collect(),
+            acceleration: BigUint::one(),
+            bounds: false,
+        })
+    }
+
+    /// `acceleration` sets the speed gained per millisecond of holding the
+    /// button, and `bounds` lists the winning holds of every race.
+    fn configure(sheet: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["acceleration", "bounds"])?;
+        if let Some(acceleration) = params.get("acceleration")? {
+            sheet.acceleration = acceleration;
+        }
+        sheet.bounds = params.get("bounds")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(sheet: &Self::Input) -> Result<Answer> {
+        Ok(sheet.num_winning(&sheet.races))
+    }
+
+    fn part2(sheet: &Self::Input) -> Result<Answer> {
+        let race = Race::new(
+            unkerned(sheet.races.iter().map(|x| &x.time)),
+            unkerned(sheet.races.iter().map(|x| &x.distance)),
+        );
+        Ok(sheet.num_winning(&[race]))
+    }
+}
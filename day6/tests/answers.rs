@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day6::Day6>(env!("CARGO_MANIFEST_DIR"));
+}
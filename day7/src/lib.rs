@@ -1,9 +1,6 @@
+use solution::{Answer, Error, Result, Solution};
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::env;
-use std::fs;
-use std::process;
-use std::str::FromStr;
 
 #[derive(Ord, PartialOrd, PartialEq, Eq, Debug)]
 enum HandType {
@@ -44,7 +41,7 @@ impl From<Vec<usize>> for HandType {
 }
 
 impl HandType {
-    fn to_new(&self, numjokers: usize) -> HandType {
+    fn with_jokers(&self, numjokers: usize) -> HandType {
         match *self {
             HandType::Five => HandType::Five,
             HandType::Four => HandType::Five,
@@ -71,17 +68,12 @@ struct Hand {
     handtype: HandType,
 }
 
-#[derive(Debug)]
-struct HandErr;
-
-impl FromStr for Hand {
-    type Err = HandErr;
-
-    fn from_str(s: &str) -> Result<Hand, HandErr> {
-        let (cards, bid) = s.split_once(" ").unwrap();
+impl Hand {
+    /// With `jokers`, J is the weakest card but counts as whatever makes the best hand.
+    fn new(cards: &str, bid: usize, jokers: bool) -> Result<Hand> {
         let mut hand = Hand {
             cards: Vec::new(),
-            bid: bid.parse::<usize>().unwrap(),
+            bid,
             handtype: HandType::Unknown,
         };
         let mut counts: HashMap<u8, usize> = HashMap::new();
@@ -90,21 +82,18 @@ impl FromStr for Hand {
                 'A' => 14,
                 'K' => 13,
                 'Q' => 12,
+                'J' if jokers => 1,
+                'J' => 11,
                 'T' => 10,
                 '2'..='9' => c.to_digit(10).unwrap().try_into().unwrap(),
-                'J' => 1,
-                _ => return Err(HandErr),
+                _ => return Err(Error::Input(format!("Unknown card {c:?} in {cards}"))),
             };
-            if let Some(val) = counts.get_mut(&card) {
-                *val += 1;
-            } else {
-                counts.insert(card, 1);
-            }
+            *counts.entry(card).or_insert(0) += 1;
             hand.cards.push(card);
         }
         hand.handtype = HandType::from(counts.values().cloned().collect::<Vec<usize>>());
         if let Some(jokers) = counts.get(&1) {
-            hand.handtype = hand.handtype.to_new(*jokers);
+            hand.handtype = hand.handtype.with_jokers(*jokers);
         }
 
         Ok(hand)
@@ -138,38 +127,45 @@ impl PartialEq for Hand {
 }
 impl Eq for Hand {}
 
-fn read_input(filename: &str) -> Vec<String> {
-    let mut ret: Vec<String> = Vec::new();
-    let content = fs::read_to_string(filename).expect("Unable to read from file.");
-    for line in content.lines() {
-        ret.push(line.trim().to_string());
-    }
-
-    ret
-}
+pub struct Day7;
 
-fn parse_input(contents: &Vec<String>) -> Vec<Hand> {
-    contents
+fn winnings(bids: &[(String, usize)], jokers: bool) -> Result<Answer> {
+    let mut hands = bids
         .iter()
-        .map(|x| Hand::from_str(x).unwrap())
-        .collect()
-}
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        println!("Wrong number of args");
-        process::exit(1);
+        .map(|(cards, bid)| Hand::new(cards, *bid, jokers))
+        .collect::<Result<Vec<Hand>>>()?;
+    hands.sort();
+
+    let mut total: usize = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        total += hand.bid * (i + 1);
     }
+    Ok(total.into())
+}
 
-    let contents = read_input(&args[1]);
-    let mut hands = parse_input(&contents);
+impl Solution for Day7 {
+    type Input = Vec<(String, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        solution::lines(input)
+            .iter()
+            .map(|line| {
+                let (cards, bid) = line
+                    .split_once(' ')
+                    .ok_or_else(|| Error::Input(format!("No bid in {line:?}")))?;
+                let bid = bid
+                    .parse::<usize>()
+                    .map_err(|_| Error::Input(format!("Invalid bid in {line:?}")))?;
+                Ok((cards.to_string(), bid))
+            })
+            .collect()
+    }
 
-    hands.sort();
-    println!("{:?}", hands);
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        winnings(input, false)
+    }
 
-    let mut total: usize = 0;
-    for i in 1..=hands.len() {
-        total += hands[i - 1].bid * i;
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        winnings(input, true)
     }
-    println!("{}", total);
 }
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day7::Day7>(env!("CARGO_MANIFEST_DIR"));
+}
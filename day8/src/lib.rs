@@ -0,0 +1,191 @@
+use solution::{Answer, Error, Result, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    neighbors: HashMap<Direction, String>,
+}
+
+#[derive(Debug)]
+struct NodeErr;
+
+impl FromStr for Node {
+    type Err = NodeErr;
+
+    fn from_str(s: &str) -> std::result::Result<Self, NodeErr> {
+        let (id, neighbors) = s.split_once(" = ").ok_or(NodeErr)?;
+        let (left, right) = neighbors.split_once(", ").ok_or(NodeErr)?;
+        Ok(Node {
+            id: id.to_string(),
+            neighbors: HashMap::from([
+                (
+                    Direction::Left,
+                    left.chars().filter(|x| x.is_alphanumeric()).collect(),
+                ),
+                (
+                    Direction::Right,
+                    right.chars().filter(|x| x.is_alphanumeric()).collect(),
+                ),
+            ]),
+        })
+    }
+}
+
+impl Node {
+    fn is_start(&self) -> bool {
+        self.id.ends_with('A')
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Path {
+    nodes: Vec<String>,
+    path_loop: Option<(usize, usize)>,
+}
+
+impl Path {
+    fn new() -> Self {
+        Path {
+            nodes: Vec::new(),
+            path_loop: None,
+        }
+    }
+
+    fn append(&mut self, node: &str) -> bool {
+        if self.has_loop() {
+            return false;
+        }
+
+        if node.ends_with('Z') && self.nodes.contains(&node.to_string()) {
+            let pos = self
+                .nodes
+                .iter()
+                .position(|x| x == &node.to_string())
+                .unwrap();
+            self.path_loop = Some((pos, self.nodes.len() - pos));
+            false
+        } else {
+            self.nodes.push(node.to_string());
+            true
+        }
+    }
+
+    fn has_loop(&self) -> bool {
+        self.path_loop.is_some()
+    }
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    nodes: HashMap<String, Node>,
+}
+
+pub struct Day8;
+
+// Taken from https://github.com/TheAlgorithms/Rust/blob/master/src/math/lcm_of_n_numbers.rs
+pub fn lcm(nums: &[usize]) -> usize {
+    if nums.len() == 1 {
+        return nums[0];
+    }
+    let a = nums[0];
+    let b = lcm(&nums[1..]);
+    a * b / gcd_of_two_numbers(a, b)
+}
+
+fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    gcd_of_two_numbers(b, a % b)
+}
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let contents = solution::lines(input);
+        let mut iter = contents.iter();
+        let directions: Vec<Direction> = iter
+            .next()
+            .ok_or_else(|| Error::Input("Empty input".to_string()))?
+            .chars()
+            .map(|x| match x {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(Error::Input(format!("Invalid direction {x:?}"))),
+            })
+            .collect::<Result<_>>()?;
+
+        iter.next();
+
+        let mut nodes: HashMap<String, Node> = HashMap::new();
+        for line in iter {
+            let node =
+                Node::from_str(line).map_err(|_| Error::Input(format!("Invalid node {line:?}")))?;
+            nodes.insert(node.id.clone(), node);
+        }
+        if directions.is_empty() {
+            return Err(Error::Input("No directions".to_string()));
+        }
+        for node in nodes.values() {
+            if let Some(to) = node.neighbors.values().find(|x| !nodes.contains_key(*x)) {
+                return Err(Error::Input(format!(
+                    "{} leads to unknown node {to:?}",
+                    node.id
+                )));
+            }
+        }
+
+        Ok(Network { directions, nodes })
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer> {
+        let nodes = &network.nodes;
+        let mut node = nodes
+            .get("AAA")
+            .ok_or_else(|| Error::Input("No AAA node".to_string()))?;
+        let mut directions_iter = network.directions.iter().cycle();
+        let mut num_steps: usize = 0;
+        while node.id != "ZZZ" {
+            let nextnode_id = node.neighbors.get(directions_iter.next().unwrap()).unwrap();
+            node = nodes.get(nextnode_id).unwrap();
+            num_steps += 1;
+        }
+
+        Ok(num_steps.into())
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer> {
+        let nodes = &network.nodes;
+        let mut curnodes: Vec<&Node> = nodes.values().filter(|x| x.is_start()).collect();
+        let mut directions_iter = network.directions.iter().cycle();
+        let mut paths: Vec<Path> = curnodes.iter().map(|_| Path::new()).collect();
+
+        while !paths.iter().all(|x| x.has_loop()) {
+            let direction = directions_iter.next().unwrap();
+            let nextnode_ids: Vec<&String> = curnodes
+                .iter()
+                .map(|x| x.neighbors.get(direction).unwrap())
+                .collect();
+
+            for (path, next) in paths.iter_mut().zip(&nextnode_ids) {
+                path.append(next);
+            }
+            curnodes = nextnode_ids
+                .iter()
+                .map(|x| nodes.get(*x).unwrap())
+                .collect();
+        }
+
+        let path_lengths: Vec<usize> = paths.iter().map(|x| x.path_loop.unwrap().1).collect();
+        Ok(lcm(&path_lengths).into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day8::Day8>(env!("CARGO_MANIFEST_DIR"));
+}
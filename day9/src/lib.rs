@@ -0,0 +1,86 @@
+mod sequence;
+
+pub use sequence::Fit;
+use solution::{Answer, Error, Params, Result, Solution};
+
+pub struct Report {
+    histories: Vec<Vec<i128>>,
+    steps: i128,
+    degrees: bool,
+}
+
+impl Report {
+    /// Sum of every history extrapolated to `index(len)`, where `len` is how
+    /// many values it has.
+    fn extrapolate(&self, index: impl Fn(i128) -> i128) -> Result<Answer> {
+        let mut sum: i128 = 0;
+        let mut details = Vec::new();
+        for (i, history) in self.histories.iter().enumerate() {
+            let overflow = || Error::Input(format!("History {} overflows", i + 1));
+            let fit = Fit::new(history).ok_or_else(overflow)?;
+            let value = fit.at(index(history.len() as i128)).ok_or_else(overflow)?;
+            sum = sum.checked_add(value).ok_or_else(overflow)?;
+
+            if self.degrees {
+                details.push(if fit.is_polynomial() {
+                    format!("History {}: degree {}", i + 1, fit.degree())
+                } else {
+                    format!(
+                        "History {}: not polynomial, fitted with degree {}",
+                        i + 1,
+                        fit.degree()
+                    )
+                });
+            }
+        }
+
+        let answer = Answer::from(sum);
+        if details.is_empty() {
+            return Ok(answer);
+        }
+        Ok(answer.with_details(details.join("\n")))
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let histories = solution::lines(input)
+            .iter()
+            .filter(|x| !x.is_empty())
+            .map(|x| {
+                x.split_whitespace()
+                    .map(|z| {
+                        z.parse()
+                            .map_err(|_| Error::Input(format!("Invalid value {z:?}")))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+        Ok(Report {
+            histories,
+            steps: 1,
+            degrees: false,
+        })
+    }
+
+    /// `steps` sets how far past either end the histories get extrapolated,
+    /// and `degrees` shows the degree of the polynomial each one follows.
+    fn configure(report: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&["steps", "degrees"])?;
+        report.steps = params.get::<u64>("steps")?.unwrap_or(1).into();
+        report.degrees = params.get("degrees")?.unwrap_or(false);
+        Ok(())
+    }
+
+    fn part1(report: &Self::Input) -> Result<Answer> {
+        report.extrapolate(|len| len - 1 + report.steps)
+    }
+
+    fn part2(report: &Self::Input) -> Result<Answer> {
+        report.extrapolate(|_| -report.steps)
+    }
+}
@@ -0,0 +1,57 @@
+/// The polynomial of lowest degree going through a sequence, found from its
+/// finite differences, in Newton's form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fit {
+    /// First value of the sequence and of each row of differences, up to
+    /// the first constant row.
+    leading: Vec<i128>,
+    /// Whether the constant row has more than one value, so the sequence
+    /// is known to follow the polynomial rather than just fit it.
+    polynomial: bool,
+}
+
+impl Fit {
+    /// `None` for an empty sequence, or if the differences overflow.
+    pub fn new(values: &[i128]) -> Option<Self> {
+        let mut row = values.to_vec();
+        let mut leading = Vec::new();
+        loop {
+            leading.push(*row.first()?);
+            if row.iter().all(|x| *x == row[0]) {
+                return Some(Fit {
+                    leading,
+                    polynomial: row.len() > 1,
+                });
+            }
+            row = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]))
+                .collect::<Option<_>>()?;
+        }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.leading.len() - 1
+    }
+
+    pub fn is_polynomial(&self) -> bool {
+        self.polynomial
+    }
+
+    /// Value at `index` of the sequence, where the first value is at 0,
+    /// or `None` on overflow.
+    ///
+    /// Sums `C(index, k)` times the leading value of the `k`th differences.
+    pub fn at(&self, index: i128) -> Option<i128> {
+        let mut ret: i128 = 0;
+        let mut binomial: i128 = 1;
+        for (k, leading) in self.leading.iter().enumerate() {
+            if k > 0 {
+                let k = k as i128;
+                binomial = binomial.checked_mul(index - (k - 1))? / k;
+            }
+            ret = ret.checked_add(binomial.checked_mul(*leading)?)?;
+        }
+        Some(ret)
+    }
+}
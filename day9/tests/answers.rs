@@ -0,0 +1,4 @@
+#[test]
+fn answers() {
+    solution::check_answers::<day9::Day9>(env!("CARGO_MANIFEST_DIR"));
+}
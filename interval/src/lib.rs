@@ -0,0 +1,234 @@
+use std::cmp::{max, min};
+
+mod mapping;
+pub use mapping::Mapping;
+
+/// The integers from `start` up to, but not including, `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn len(&self) -> i64 {
+        max(self.end - self.start, 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// The part of `self` also in `other`, if any.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let ret = Interval::new(max(self.start, other.start), min(self.end, other.end));
+        (!ret.is_empty()).then_some(ret)
+    }
+
+    /// Every element moved by `offset`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor
+/// touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval> = iter.into_iter().filter(|x| !x.is_empty()).collect();
+        intervals.sort();
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = max(last.end, interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> + '_ {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(|x| x.len()).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|x| x.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|x| x.end - 1)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= x);
+        self.intervals.get(idx).is_some_and(|i| i.contains(x))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ret = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            ret.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals: ret }
+    }
+
+    /// Elements of `self` that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ret = Vec::new();
+        let mut others = other.intervals.iter().peekable();
+        for interval in self.intervals.iter() {
+            let mut start = interval.start;
+            while let Some(o) = others.peek() {
+                if o.end <= start {
+                    others.next();
+                    continue;
+                }
+                if o.start >= interval.end {
+                    break;
+                }
+                if o.start > start {
+                    ret.push(Interval::new(start, o.start));
+                }
+                start = o.end;
+                if o.end > interval.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < interval.end {
+                ret.push(Interval::new(start, interval.end));
+            }
+        }
+        IntervalSet { intervals: ret }
+    }
+
+    /// Every element moved by `offset`.
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self.intervals.iter().map(|x| x.shift(offset)).collect(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet) -> Vec<(i64, i64)> {
+        set.iter().map(|x| (x.start, x.end)).collect()
+    }
+
+    #[test]
+    fn touching_and_overlapping_intervals_merge() {
+        assert_eq!(intervals(&set(&[(5, 8), (0, 5)])), [(0, 8)]);
+        assert_eq!(
+            intervals(&set(&[(0, 5), (3, 8), (10, 12)])),
+            [(0, 8), (10, 12)]
+        );
+        assert_eq!(intervals(&set(&[(0, 10), (2, 4), (4, 4)])), [(0, 10)]);
+    }
+
+    #[test]
+    fn union() {
+        let a = set(&[(0, 3), (10, 12)]);
+        let b = set(&[(3, 5), (11, 15), (20, 21)]);
+        assert_eq!(intervals(&a.union(&b)), [(0, 5), (10, 15), (20, 21)]);
+        assert_eq!(a.union(&IntervalSet::new()), a);
+        assert_eq!(a.union(&b).len(), 5 + 5 + 1);
+    }
+
+    #[test]
+    fn contains() {
+        let a = set(&[(0, 3), (10, 12)]);
+        for (x, expected) in [
+            (-1, false),
+            (0, true),
+            (2, true),
+            (3, false),
+            (9, false),
+            (10, true),
+            (11, true),
+            (12, false),
+        ] {
+            assert_eq!(a.contains(x), expected, "{x}");
+        }
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(intervals(&a.intersection(&b)), [(3, 5), (10, 12), (14, 15)]);
+        assert!(a.intersection(&set(&[(5, 10)])).is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(-5, 2), (4, 6), (9, 22), (25, 26), (29, 40)]);
+        assert_eq!(
+            intervals(&a.difference(&b)),
+            [(2, 4), (6, 9), (22, 25), (26, 29)]
+        );
+        // Touching but not overlapping removes nothing
+        assert_eq!(a.difference(&set(&[(10, 20)])), a);
+        assert!(a.difference(&set(&[(0, 30)])).is_empty());
+    }
+}
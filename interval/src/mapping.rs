@@ -0,0 +1,157 @@
+use crate::{Interval, IntervalSet};
+
+/// A function on the integers that is the identity except on some disjoint
+/// intervals, each of which it shifts by its own offset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mapping {
+    /// Sorted, disjoint and without empty intervals or zero offsets.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl Mapping {
+    /// The identity.
+    pub fn new() -> Self {
+        Mapping::default()
+    }
+
+    /// The mapping shifting each interval by its offset, or None if two of
+    /// the intervals overlap.
+    pub fn from_pieces(pieces: impl IntoIterator<Item = (Interval, i64)>) -> Option<Self> {
+        let mut pieces: Vec<(Interval, i64)> = pieces
+            .into_iter()
+            .filter(|(interval, _)| !interval.is_empty())
+            .collect();
+        pieces.sort();
+        if pieces.windows(2).any(|w| w[0].0.end > w[1].0.start) {
+            return None;
+        }
+        pieces.retain(|(_, offset)| *offset != 0);
+        Some(Mapping { pieces })
+    }
+
+    pub fn pieces(&self) -> &[(Interval, i64)] {
+        &self.pieces
+    }
+
+    /// Where the mapping moves anything.
+    pub fn domain(&self) -> IntervalSet {
+        self.pieces.iter().map(|(interval, _)| *interval).collect()
+    }
+
+    pub fn apply(&self, x: i64) -> i64 {
+        let idx = self.pieces.partition_point(|(i, _)| i.end <= x);
+        match self.pieces.get(idx) {
+            Some((interval, offset)) if interval.contains(x) => x + offset,
+            _ => x,
+        }
+    }
+
+    /// Where the elements of `set` end up.
+    pub fn image(&self, set: &IntervalSet) -> IntervalSet {
+        let moved = self.pieces.iter().flat_map(|(interval, offset)| {
+            set.intersection(&IntervalSet::from(*interval))
+                .shift(*offset)
+                .intervals
+        });
+        let unmoved = set.difference(&self.domain()).intervals;
+        moved.chain(unmoved).collect()
+    }
+
+    /// The mapping applying `self`, then `next`.
+    pub fn then(&self, next: &Mapping) -> Mapping {
+        let mut pieces = Vec::new();
+        for (interval, offset) in self.pieces.iter() {
+            let image = IntervalSet::from(interval.shift(*offset));
+            for (next_interval, next_offset) in next.pieces.iter() {
+                for i in image
+                    .intersection(&IntervalSet::from(*next_interval))
+                    .iter()
+                {
+                    pieces.push((i.shift(-offset), offset + next_offset));
+                }
+            }
+            for i in image.difference(&next.domain()).iter() {
+                pieces.push((i.shift(-offset), *offset));
+            }
+        }
+
+        let untouched = self.domain();
+        for (next_interval, next_offset) in next.pieces.iter() {
+            for i in IntervalSet::from(*next_interval)
+                .difference(&untouched)
+                .iter()
+            {
+                pieces.push((*i, *next_offset));
+            }
+        }
+        Mapping::from_pieces(pieces).expect("Composed pieces can't overlap")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(pieces: &[(i64, i64, i64)]) -> Mapping {
+        Mapping::from_pieces(
+            pieces
+                .iter()
+                .map(|&(start, end, offset)| (Interval::new(start, end), offset)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn overlapping_pieces_are_rejected() {
+        assert!(
+            Mapping::from_pieces([(Interval::new(0, 5), 1), (Interval::new(4, 8), 2)]).is_none()
+        );
+        assert!(
+            Mapping::from_pieces([(Interval::new(0, 5), 1), (Interval::new(5, 8), 2)]).is_some()
+        );
+    }
+
+    #[test]
+    fn then_with_partly_overlapping_pieces() {
+        // [0, 10) lands on [5, 15), partly in each of next's pieces
+        let first = mapping(&[(0, 10, 5), (20, 25, -20)]);
+        let next = mapping(&[(3, 8, 100), (12, 30, -12)]);
+        let composed = first.then(&next);
+
+        for x in -5..40 {
+            assert_eq!(composed.apply(x), next.apply(first.apply(x)), "{x}");
+        }
+        assert_eq!(
+            composed.pieces(),
+            [
+                (Interval::new(0, 3), 105),
+                (Interval::new(3, 7), 5),
+                (Interval::new(7, 10), -7),
+                (Interval::new(12, 20), -12),
+                (Interval::new(20, 23), -20),
+                (Interval::new(23, 25), 80),
+                (Interval::new(25, 30), -12),
+            ]
+            .as_slice()
+        );
+    }
+
+    #[test]
+    fn then_cancelling_offsets_drop_out() {
+        let first = mapping(&[(0, 10, 5)]);
+        let next = mapping(&[(5, 15, -5)]);
+        assert_eq!(first.then(&next), mapping(&[(10, 15, -5)]));
+        assert_eq!(
+            first.then(&mapping(&[(5, 15, -5), (-5, 0, 10)])),
+            mapping(&[(10, 15, -5), (-5, 0, 10)])
+        );
+    }
+
+    #[test]
+    fn image_of_a_set() {
+        let m = mapping(&[(0, 10, 5), (20, 25, -20)]);
+        let set: IntervalSet = [Interval::new(8, 22)].into_iter().collect();
+        let image: Vec<Interval> = m.image(&set).iter().copied().collect();
+        assert_eq!(image, [Interval::new(0, 2), Interval::new(10, 20)]);
+    }
+}
@@ -1,112 +1,187 @@
 use std::fmt;
-use std::iter::FromIterator;
+use std::iter::{FromIterator, StepBy};
+use std::ops::Index;
+use std::slice;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+mod tiled;
+pub use tiled::{TiledView, ORTHOGONAL, SURROUNDING};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Matrix<T> {
-    contents: Vec<Vec<T>>,
+    contents: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// A strided, borrowed view over one column of a `Matrix`.
+#[derive(Debug, Clone, Copy)]
+pub struct Column<'a, T> {
+    contents: &'a [T],
+    width: usize,
+    height: usize,
 }
 
 impl<T: fmt::Display> fmt::Display for Matrix<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in &self.contents {
+        for row in self.rows() {
             for col in row {
                 write!(f, "{col}")?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
 }
 
-impl<T: Clone> FromIterator<Vec<T>> for Matrix<T> {
+impl<T> FromIterator<Vec<T>> for Matrix<T> {
     fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
         let mut ret = Matrix {
             contents: Vec::new(),
+            width: 0,
+            height: 0,
         };
 
         for elt in iter {
-            ret.contents.push(elt);
+            ret.push_row(elt);
         }
 
         ret
     }
 }
 
-impl<T: Clone + PartialEq> Matrix<T> {
+impl<T> Index<[usize; 2]> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &T {
+        self.get(index[0], index[1])
+            .expect("Matrix index out of bounds")
+    }
+}
+
+impl<T> Matrix<T> {
     pub fn new(c: Vec<Vec<T>>) -> Matrix<T> {
-        Matrix {
-            contents: c.clone(),
-        }
+        Matrix::from_iter(c)
     }
 
-    pub fn get(&self, x: usize, y: usize) -> Option<T> {
-        if y >= self.contents.len() || x >= self.contents[0].len() {
-            return None;
+    /// Like `new`, but instead of panicking on rows of different lengths,
+    /// fails with the index of the first one that isn't as long as the first.
+    pub fn try_new(c: impl IntoIterator<Item = Vec<T>>) -> Result<Matrix<T>, usize> {
+        let mut ret = Matrix {
+            contents: Vec::new(),
+            width: 0,
+            height: 0,
+        };
+        for (y, row) in c.into_iter().enumerate() {
+            if y > 0 && row.len() != ret.width {
+                return Err(y);
+            }
+            ret.push_row(row);
         }
+        Ok(ret)
+    }
 
-        Some(self.contents[y][x].clone())
+    fn push_row(&mut self, row: Vec<T>) {
+        if self.height == 0 {
+            self.width = row.len();
+        }
+        assert_eq!(
+            row.len(),
+            self.width,
+            "Matrix rows must all have the same length"
+        );
+        self.contents.extend(row);
+        self.height += 1;
     }
 
-    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
-        if y >= self.contents.len() || x >= self.contents[0].len() {
+    fn offset(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.width || y >= self.height {
             return None;
         }
 
-        Some(&mut self.contents[y][x])
+        Some(y * self.width + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.offset(x, y).map(|i| &self.contents[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.offset(x, y).map(|i| &mut self.contents[i])
     }
 
     pub fn set(&mut self, x: usize, y: usize, val: T) {
-        self.contents[y][x] = val;
+        *self.get_mut(x, y).expect("Matrix index out of bounds") = val;
     }
 
     pub fn num_rows(&self) -> usize {
-        self.contents.len()
+        self.height
     }
 
     pub fn num_cols(&self) -> usize {
-        self.contents[0].len()
+        self.width
     }
 
-    pub fn row(&self, idx: usize) -> Option<Vec<T>> {
-        if idx >= self.contents.len() {
+    pub fn row(&self, idx: usize) -> Option<&[T]> {
+        if idx >= self.height {
             return None;
         }
 
-        Some(self.contents[idx].clone())
+        Some(&self.contents[idx * self.width..(idx + 1) * self.width])
     }
 
-    pub fn col(&self, idx: usize) -> Option<Vec<T>> {
-        if idx >= self.contents[0].len() {
+    pub fn col(&self, idx: usize) -> Option<Column<'_, T>> {
+        if idx >= self.width {
             return None;
         }
 
-        Some(self.contents.iter().map(|x| x[idx].clone()).collect())
+        Some(Column {
+            contents: &self.contents[idx..],
+            width: self.width,
+            height: self.height,
+        })
     }
 
     pub fn insert_row(&mut self, idx: usize, content: Vec<T>) {
-        self.contents.insert(idx, content.clone());
+        if self.height == 0 {
+            self.width = content.len();
+        }
+        assert_eq!(
+            content.len(),
+            self.width,
+            "Inserted row has the wrong length"
+        );
+        let at = idx * self.width;
+        self.contents.splice(at..at, content);
+        self.height += 1;
     }
 
     pub fn insert_col(&mut self, idx: usize, content: Vec<T>) {
-        for (i, val) in content.iter().enumerate() {
-            self.contents[i].insert(idx, val.clone());
+        assert_eq!(
+            content.len(),
+            self.height,
+            "Inserted column has the wrong length"
+        );
+        let width = self.width;
+        let mut old = std::mem::take(&mut self.contents).into_iter();
+        let mut new = content.into_iter();
+        self.contents = Vec::with_capacity((width + 1) * self.height);
+        for _ in 0..self.height {
+            self.contents.extend(old.by_ref().take(idx));
+            self.contents.extend(new.next());
+            self.contents.extend(old.by_ref().take(width - idx));
         }
+        self.width += 1;
     }
 
-    pub fn cols(&self) -> impl DoubleEndedIterator<Item = Vec<T>> + '_ {
-        (0..self.contents[0].len()).map(|x| {
-            self.contents
-                .iter()
-                .map(|row| row[x].clone())
-                .collect::<Vec<T>>()
-        })
+    pub fn cols(&self) -> impl DoubleEndedIterator<Item = Column<'_, T>> + '_ {
+        (0..self.width).map(|x| self.col(x).unwrap())
     }
 
-    pub fn rows(&self) -> impl DoubleEndedIterator<Item = Vec<T>> + '_ {
-        self.contents.iter().cloned()
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y).unwrap())
     }
 
-    pub fn get_neighbors(&self, x: usize, y: usize) -> Vec<([usize; 2], T)> {
+    pub fn get_neighbors(&self, x: usize, y: usize) -> Vec<([usize; 2], &T)> {
         let mut ret = Vec::new();
         if x > 0 {
             ret.push(([x - 1, y], self.get(x - 1, y).unwrap()));
@@ -123,49 +198,126 @@ impl<T: Clone + PartialEq> Matrix<T> {
         ret
     }
 
-    pub fn get_neighbors_wraparound(&self, x: i32, y: i32) -> Vec<([i32; 2], T)> {
-        let coords = [[x - 1, y], [x + 1, y], [x, y - 1], [x, y + 1]];
-        let mut ret = Vec::new();
-        if x == -10 {
-            println!("");
-        }
-        for coord in coords {
-            let mut inside_coord = coord;
-            if coord[0] <= -i32::try_from(self.num_cols()).unwrap() {
-                inside_coord[0] = i32::try_from(self.num_cols()).unwrap() - 1
-                    + (coord[0] % (i32::try_from(self.num_cols()).unwrap()));
-            } else if coord[0] < 0 {
-                inside_coord[0] = i32::try_from(self.num_cols()).unwrap() + coord[0];
-            } else if coord[0] >= self.num_cols().try_into().unwrap() {
-                inside_coord[0] = (coord[0]) % (i32::try_from(self.num_cols()).unwrap());
-            }
+    pub fn tiled(&self) -> TiledView<'_, T> {
+        TiledView::new(self)
+    }
+}
 
-            if coord[1] <= -i32::try_from(self.num_rows()).unwrap() {
-                inside_coord[1] = (i32::try_from(self.num_rows()).unwrap()) - 1
-                    + (coord[1] % (i32::try_from(self.num_rows()).unwrap()));
-            } else if coord[1] < 0 {
-                inside_coord[1] = i32::try_from(self.num_cols()).unwrap() + coord[1];
-            } else if coord[1] >= self.num_rows().try_into().unwrap() {
-                inside_coord[1] = (coord[1]) % (i32::try_from(self.num_rows()).unwrap());
-            }
-            ret.push((
-                coord,
-                self.get(
-                    inside_coord[0].try_into().unwrap(),
-                    inside_coord[1].try_into().unwrap(),
-                )
-                .unwrap(),
-            ));
+impl<T: PartialEq> Matrix<T> {
+    pub fn find(&self, needle: T) -> Option<[usize; 2]> {
+        self.contents
+            .iter()
+            .position(|x| x == &needle)
+            .map(|i| [i % self.width, i / self.width])
+    }
+}
+
+impl<'a, T> Column<'a, T> {
+    pub fn len(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.height == 0
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&'a T> {
+        if idx >= self.height {
+            return None;
         }
-        ret
+
+        Some(&self.contents[idx * self.width])
     }
 
-    pub fn find(&self, needle: T) -> Option<[usize; 2]> {
-        for (y, row) in self.rows().enumerate() {
-            if let Some(x) = row.iter().position(|x| x == &needle) {
-                return Some([x, y]);
-            }
+    pub fn iter(&self) -> StepBy<slice::Iter<'a, T>> {
+        self.contents.iter().step_by(self.width)
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<'a, T> IntoIterator for Column<'a, T> {
+    type Item = &'a T;
+    type IntoIter = StepBy<slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Index<usize> for Column<'_, T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        self.get(idx).expect("Column index out of bounds")
+    }
+}
+
+impl<T: PartialEq> PartialEq for Column<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Column<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Matrix<u32> {
+        Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn insert_row_anywhere() {
+        for (idx, expected) in [
+            (0, vec![vec![0, 0, 0], vec![1, 2, 3], vec![4, 5, 6]]),
+            (1, vec![vec![1, 2, 3], vec![0, 0, 0], vec![4, 5, 6]]),
+            (2, vec![vec![1, 2, 3], vec![4, 5, 6], vec![0, 0, 0]]),
+        ] {
+            let mut matrix = grid();
+            matrix.insert_row(idx, vec![0; 3]);
+            assert_eq!(matrix, Matrix::new(expected));
+            assert_eq!([matrix.num_cols(), matrix.num_rows()], [3, 3]);
         }
-        None
+    }
+
+    #[test]
+    fn insert_col_anywhere() {
+        for (idx, expected) in [
+            (0, vec![vec![7, 1, 2, 3], vec![8, 4, 5, 6]]),
+            (1, vec![vec![1, 7, 2, 3], vec![4, 8, 5, 6]]),
+            (3, vec![vec![1, 2, 3, 7], vec![4, 5, 6, 8]]),
+        ] {
+            let mut matrix = grid();
+            matrix.insert_col(idx, vec![7, 8]);
+            assert_eq!(matrix, Matrix::new(expected));
+            assert_eq!(matrix.col(idx).unwrap().to_vec(), [7, 8]);
+        }
+    }
+
+    #[test]
+    fn insert_row_into_empty() {
+        let mut matrix: Matrix<u32> = Matrix::new(Vec::new());
+        matrix.insert_row(0, vec![1, 2]);
+        assert_eq!(matrix, Matrix::new(vec![vec![1, 2]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Inserted row has the wrong length")]
+    fn insert_row_wrong_length() {
+        grid().insert_row(1, vec![0; 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Inserted column has the wrong length")]
+    fn insert_col_wrong_length() {
+        grid().insert_col(1, vec![0; 3]);
     }
 }
@@ -0,0 +1,135 @@
+use crate::Matrix;
+
+/// Offsets of the 4 orthogonal neighbours: left, right, up, down.
+pub const ORTHOGONAL: [[i64; 2]; 4] = [[-1, 0], [1, 0], [0, -1], [0, 1]];
+
+/// Offsets of the 8 surrounding cells, orthogonal ones first.
+pub const SURROUNDING: [[i64; 2]; 8] = [
+    [-1, 0],
+    [1, 0],
+    [0, -1],
+    [0, 1],
+    [-1, -1],
+    [1, -1],
+    [-1, 1],
+    [1, 1],
+];
+
+/// An infinite plane made of copies of a `Matrix`, addressed with signed coordinates.
+///
+/// The copy containing `[0, 0]` is tile `[0, 0]`, the one to its left is
+/// tile `[-1, 0]` and so on.
+#[derive(Debug, Clone, Copy)]
+pub struct TiledView<'a, T> {
+    matrix: &'a Matrix<T>,
+}
+
+impl<'a, T> TiledView<'a, T> {
+    pub fn new(matrix: &'a Matrix<T>) -> Self {
+        assert!(
+            matrix.num_cols() > 0 && matrix.num_rows() > 0,
+            "Cannot tile an empty matrix"
+        );
+        TiledView { matrix }
+    }
+
+    pub fn matrix(&self) -> &'a Matrix<T> {
+        self.matrix
+    }
+
+    fn size(&self) -> [i64; 2] {
+        [
+            i64::try_from(self.matrix.num_cols()).unwrap(),
+            i64::try_from(self.matrix.num_rows()).unwrap(),
+        ]
+    }
+
+    /// Position of `[x, y]` inside the base tile.
+    pub fn wrap(&self, x: i64, y: i64) -> [usize; 2] {
+        let [w, h] = self.size();
+        [
+            usize::try_from(x.rem_euclid(w)).unwrap(),
+            usize::try_from(y.rem_euclid(h)).unwrap(),
+        ]
+    }
+
+    /// Which copy of the base tile `[x, y]` falls in.
+    pub fn tile(&self, x: i64, y: i64) -> [i64; 2] {
+        let [w, h] = self.size();
+        [x.div_euclid(w), y.div_euclid(h)]
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> &'a T {
+        let [bx, by] = self.wrap(x, y);
+        self.matrix.get(bx, by).unwrap()
+    }
+
+    /// The 4 orthogonal neighbours of `[x, y]` with their values.
+    pub fn neighbors(&self, x: i64, y: i64) -> impl Iterator<Item = ([i64; 2], &'a T)> + '_ {
+        self.around(x, y, &ORTHOGONAL)
+    }
+
+    /// The 8 cells surrounding `[x, y]` with their values.
+    pub fn surrounding(&self, x: i64, y: i64) -> impl Iterator<Item = ([i64; 2], &'a T)> + '_ {
+        self.around(x, y, &SURROUNDING)
+    }
+
+    fn around(
+        &self,
+        x: i64,
+        y: i64,
+        offsets: &'static [[i64; 2]],
+    ) -> impl Iterator<Item = ([i64; 2], &'a T)> + '_ {
+        offsets.iter().map(move |[dx, dy]| {
+            let pos = [x + dx, y + dy];
+            (pos, self.get(pos[0], pos[1]))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    fn grid() -> Matrix<u32> {
+        Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn negative_coordinates_wrap() {
+        let matrix = grid();
+        let tiled = matrix.tiled();
+        assert_eq!(tiled.tile(0, 0), [0, 0]);
+        assert_eq!(tiled.tile(-1, 0), [-1, 0]);
+        assert_eq!(tiled.tile(-3, -2), [-1, -1]);
+        assert_eq!(tiled.tile(-4, -3), [-2, -2]);
+        assert_eq!(tiled.tile(3, 2), [1, 1]);
+        assert_eq!(tiled.wrap(-1, -1), [2, 1]);
+        assert_eq!(tiled.wrap(-4, -3), [2, 1]);
+        assert_eq!(*tiled.get(-1, 0), 3);
+        assert_eq!(*tiled.get(-3, -2), 1);
+        assert_eq!(*tiled.get(7, 5), 5);
+    }
+
+    #[test]
+    fn surrounding_crosses_tiles() {
+        let matrix = grid();
+        let tiled = matrix.tiled();
+        let around: Vec<([i64; 2], u32)> = tiled.surrounding(0, 0).map(|(p, v)| (p, *v)).collect();
+        assert_eq!(
+            around,
+            [
+                ([-1, 0], 3),
+                ([1, 0], 2),
+                ([0, -1], 4),
+                ([0, 1], 4),
+                ([-1, -1], 6),
+                ([1, -1], 5),
+                ([-1, 1], 6),
+                ([1, 1], 5),
+            ]
+        );
+        let neighbors: Vec<u32> = tiled.neighbors(-1, -1).map(|(_, v)| *v).collect();
+        assert_eq!(neighbors, [5, 4, 3, 3]);
+    }
+}
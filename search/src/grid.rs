@@ -0,0 +1,123 @@
+use crate::SearchState;
+use matrix::Matrix;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Whether a step from one cell to the next is allowed.
+type Moves<'a, T> = Box<dyn Fn(&T, &T) -> bool + 'a>;
+
+/// Turns the cells of a `Matrix` into a search space where each cell leads
+/// to its 4 orthogonal neighbours.
+pub struct Grid<'a, T> {
+    matrix: &'a Matrix<T>,
+    passable: Box<dyn Fn(&T) -> bool + 'a>,
+    moves: Moves<'a, T>,
+    cost: Box<dyn Fn(&T) -> u64 + 'a>,
+    goal: Option<[usize; 2]>,
+}
+
+impl<'a, T> Grid<'a, T> {
+    /// Every cell is passable and costs 1 to enter.
+    pub fn new(matrix: &'a Matrix<T>) -> Self {
+        Grid {
+            matrix,
+            passable: Box::new(|_| true),
+            moves: Box::new(|_, _| true),
+            cost: Box::new(|_| 1),
+            goal: None,
+        }
+    }
+
+    pub fn passable(mut self, passable: impl Fn(&T) -> bool + 'a) -> Self {
+        self.passable = Box::new(passable);
+        self
+    }
+
+    /// Whether a cell leads to a passable neighbour, for cells that don't
+    /// connect to every side like pipes.
+    pub fn moves(mut self, moves: impl Fn(&T, &T) -> bool + 'a) -> Self {
+        self.moves = Box::new(moves);
+        self
+    }
+
+    /// Cost of entering a cell. The A* heuristic assumes it is at least 1.
+    pub fn cost(mut self, cost: impl Fn(&T) -> u64 + 'a) -> Self {
+        self.cost = Box::new(cost);
+        self
+    }
+
+    pub fn goal(mut self, goal: [usize; 2]) -> Self {
+        self.goal = Some(goal);
+        self
+    }
+
+    pub fn matrix(&self) -> &'a Matrix<T> {
+        self.matrix
+    }
+
+    pub fn cell(&'a self, pos: [usize; 2]) -> GridCell<'a, T> {
+        GridCell { grid: self, pos }
+    }
+}
+
+pub struct GridCell<'a, T> {
+    grid: &'a Grid<'a, T>,
+    pub pos: [usize; 2],
+}
+
+impl<T> Clone for GridCell<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridCell<'_, T> {}
+
+impl<T> PartialEq for GridCell<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pos == other.pos
+    }
+}
+
+impl<T> Eq for GridCell<'_, T> {}
+
+impl<T> Hash for GridCell<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pos.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for GridCell<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GridCell({:?})", self.pos)
+    }
+}
+
+impl<T> SearchState for GridCell<'_, T> {
+    type Cost = u64;
+
+    fn successors(&self) -> Vec<(Self, u64)> {
+        let here = &self.grid.matrix[self.pos];
+        self.grid
+            .matrix
+            .get_neighbors(self.pos[0], self.pos[1])
+            .into_iter()
+            .filter(|(_, val)| (self.grid.passable)(val) && (self.grid.moves)(here, val))
+            .map(|(pos, val)| (self.grid.cell(pos), (self.grid.cost)(val)))
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.grid.goal == Some(self.pos)
+    }
+
+    fn heuristic(&self) -> u64 {
+        match self.grid.goal {
+            Some(goal) => {
+                u64::try_from(self.pos[0].abs_diff(goal[0]) + self.pos[1].abs_diff(goal[1]))
+                    .unwrap()
+            }
+            None => 0,
+        }
+    }
+}
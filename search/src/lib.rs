@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+mod grid;
+pub use grid::{Grid, GridCell};
+
+/// A node of a search space, able to list where it can go next.
+pub trait SearchState: Clone + Eq + Hash {
+    type Cost: Copy + Ord + Add<Output = Self::Cost> + Default;
+
+    fn successors(&self) -> Vec<(Self, Self::Cost)>;
+    fn is_goal(&self) -> bool;
+
+    /// Lower bound of the cost left to reach a goal, only used by `astar`.
+    fn heuristic(&self) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// What a search found: the distance to every state it settled or queued,
+/// how each one was reached, and the goal state if one was reached.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, S>,
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> SearchResult<S, C> {
+    fn new() -> Self {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn cost(&self) -> Option<C> {
+        self.goal.as_ref().map(|goal| self.distances[goal])
+    }
+
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    /// Every state from the start up to and including `state`.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut ret = vec![state.clone()];
+        while let Some(prev) = self.predecessors.get(ret.last().unwrap()) {
+            ret.push(prev.clone());
+        }
+        ret.reverse();
+        Some(ret)
+    }
+}
+
+/// Breadth-first search, distances are counted in steps and edge costs are ignored.
+/// Explores the whole reachable space if no goal is ever found.
+pub fn bfs<S: SearchState>(start: S) -> SearchResult<S, usize> {
+    let mut result = SearchResult::new();
+    result.distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(cur) = queue.pop_front() {
+        if cur.is_goal() {
+            result.goal = Some(cur);
+            break;
+        }
+
+        let dist = result.distances[&cur];
+        for (next, _) in cur.successors() {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), dist + 1);
+                result.predecessors.insert(next.clone(), cur.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+pub fn dijkstra<S: SearchState>(start: S) -> SearchResult<S, S::Cost> {
+    best_first(start, |_| S::Cost::default())
+}
+
+/// Like `dijkstra` but guided by `SearchState::heuristic`, which must never
+/// overestimate the remaining cost.
+pub fn astar<S: SearchState>(start: S) -> SearchResult<S, S::Cost> {
+    best_first(start, S::heuristic)
+}
+
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+fn best_first<S: SearchState>(
+    start: S,
+    heuristic: impl Fn(&S) -> S::Cost,
+) -> SearchResult<S, S::Cost> {
+    let mut result = SearchResult::new();
+    let mut heap = BinaryHeap::new();
+
+    result.distances.insert(start.clone(), S::Cost::default());
+    heap.push(Entry {
+        priority: heuristic(&start),
+        cost: S::Cost::default(),
+        state: start,
+    });
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        if cost > result.distances[&state] {
+            continue;
+        }
+
+        if state.is_goal() {
+            result.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in state.successors() {
+            let next_cost = cost + step;
+            if result.distances.get(&next).is_none_or(|d| next_cost < *d) {
+                result.distances.insert(next.clone(), next_cost);
+                result.predecessors.insert(next.clone(), state.clone());
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use matrix::Matrix;
+
+    fn weights(rows: &[&str]) -> Matrix<u64> {
+        rows.iter()
+            .map(|row| {
+                row.chars()
+                    .map(|c| c.to_digit(10).unwrap().into())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dijkstra_goes_around_what_bfs_goes_through() {
+        let matrix = weights(&["191", "191", "111"]);
+        let grid = Grid::new(&matrix).cost(|x| *x).goal([2, 0]);
+
+        assert_eq!(bfs(grid.cell([0, 0])).cost(), Some(2));
+        assert_eq!(dijkstra(grid.cell([0, 0])).cost(), Some(6));
+        assert_eq!(astar(grid.cell([0, 0])).cost(), Some(6));
+    }
+
+    #[test]
+    fn path_is_rebuilt_from_the_start() {
+        let matrix = weights(&["191", "191", "111"]);
+        let grid = Grid::new(&matrix).cost(|x| *x).goal([2, 0]);
+        let result = dijkstra(grid.cell([0, 0]));
+
+        let path: Vec<[usize; 2]> = result.path().unwrap().iter().map(|x| x.pos).collect();
+        assert_eq!(
+            path,
+            [[0, 0], [0, 1], [0, 2], [1, 2], [2, 2], [2, 1], [2, 0]]
+        );
+        let path: Vec<[usize; 2]> = result
+            .path_to(&grid.cell([0, 2]))
+            .unwrap()
+            .iter()
+            .map(|x| x.pos)
+            .collect();
+        assert_eq!(path, [[0, 0], [0, 1], [0, 2]]);
+        assert_eq!(result.path_to(&grid.cell([0, 0])).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn unreachable_goal_is_none() {
+        let matrix = weights(&["191", "191", "191"]);
+        let grid = Grid::new(&matrix)
+            .passable(|x| *x != 9)
+            .cost(|x| *x)
+            .goal([2, 0]);
+
+        for result in [dijkstra(grid.cell([0, 0])), astar(grid.cell([0, 0]))] {
+            assert_eq!(result.goal, None);
+            assert_eq!(result.cost(), None);
+            assert_eq!(result.path(), None);
+            assert_eq!(result.path_to(&grid.cell([2, 2])), None);
+            assert_eq!(result.distances.len(), 3);
+        }
+        assert_eq!(bfs(grid.cell([0, 0])).cost(), None);
+    }
+
+    #[test]
+    fn adapters_capture_state() {
+        let matrix = weights(&["123", "456", "789"]);
+        let walls = [5, 6];
+        let extra = 10;
+        let grid = Grid::new(&matrix)
+            .passable(|x| !walls.contains(x))
+            .cost(|x| x + extra)
+            .goal([2, 2]);
+
+        let result = dijkstra(grid.cell([0, 0]));
+        let path: Vec<[usize; 2]> = result.path().unwrap().iter().map(|x| x.pos).collect();
+        assert_eq!(path, [[0, 0], [0, 1], [0, 2], [1, 2], [2, 2]]);
+        assert_eq!(result.cost(), Some(4 + 7 + 8 + 9 + 4 * extra));
+    }
+
+    #[test]
+    fn moves_only_between_allowed_cells() {
+        // Only ever step to the next value
+        let matrix = weights(&["14", "23"]);
+        let grid = Grid::new(&matrix)
+            .moves(|from, to| *to == from + 1)
+            .goal([1, 0]);
+
+        let path: Vec<[usize; 2]> = bfs(grid.cell([0, 0]))
+            .path()
+            .unwrap()
+            .iter()
+            .map(|x| x.pos)
+            .collect();
+        assert_eq!(path, [[0, 0], [0, 1], [1, 1], [1, 0]]);
+        assert_eq!(bfs(grid.cell([1, 1])).distances.len(), 2);
+    }
+}
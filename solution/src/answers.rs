@@ -0,0 +1,131 @@
+use crate::{Day, Params, Part, Solution};
+use std::fs;
+use std::path::Path;
+
+/// An answer recorded in a day's `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// Name of the table the answer is in.
+    pub name: String,
+    /// Name of the file in the day's `inputs/` directory.
+    pub input: String,
+    pub params: Params,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// Reads `<dir>/answers.toml`, where each table is named after an input file
+/// and holds the expected `part1` and/or `part2`. A table can also solve
+/// another `input`, with some `params`:
+///
+/// ```toml
+/// [example]
+/// part1 = 142
+///
+/// [prod]
+/// part1 = 54159
+/// part2 = "53866"
+///
+/// [example-10-steps]
+/// input = "example"
+/// params = { steps = 10 }
+/// part1 = 50
+/// ```
+pub fn expected_answers(dir: &Path) -> Result<Vec<Expected>, String> {
+    let path = dir.join("answers.toml");
+    let contents =
+        fs::read_to_string(&path).map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+    let table: toml::Table = contents
+        .parse()
+        .map_err(|e| format!("Can't parse {}: {e}", path.display()))?;
+
+    let mut ret = Vec::new();
+    for (name, entries) in table {
+        let entries = entries
+            .as_table()
+            .ok_or_else(|| format!("[{name}] should be a table"))?;
+        let mut input = name.clone();
+        let mut params = Params::new();
+        let mut answers = Vec::new();
+        for (key, value) in entries {
+            match (key.as_str(), value) {
+                ("input", toml::Value::String(s)) => input = s.clone(),
+                ("params", toml::Value::Table(t)) => {
+                    for (k, v) in t {
+                        let v = match v {
+                            toml::Value::String(s) => s.clone(),
+                            v => v.to_string(),
+                        };
+                        params.set(k, &v);
+                    }
+                }
+                (key, value) => {
+                    let part = key.parse::<Part>().map_err(|e| format!("[{name}] {e}"))?;
+                    let answer = match value {
+                        toml::Value::String(s) => s.clone(),
+                        toml::Value::Integer(i) => i.to_string(),
+                        _ => {
+                            return Err(format!("[{name}] {part} should be a string or an integer"))
+                        }
+                    };
+                    answers.push((part, answer));
+                }
+            }
+        }
+        for (part, answer) in answers {
+            ret.push(Expected {
+                name: name.clone(),
+                input: input.clone(),
+                params: params.clone(),
+                part,
+                answer,
+            });
+        }
+    }
+
+    Ok(ret)
+}
+
+/// The gitignored real puzzle input of each day, the only input allowed to be
+/// missing.
+const REAL_INPUT: &str = "prod";
+
+/// Solves every input listed in `<dir>/answers.toml` and panics listing every
+/// wrong answer or missing input. Only the real puzzle inputs may be missing,
+/// as they aren't committed, but at least one answer has to be checked.
+pub fn check_answers<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let expected = expected_answers(dir).unwrap_or_else(|e| panic!("{e}"));
+    let day = Day::new::<S>(0);
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for e in expected.iter() {
+        let path = dir.join("inputs").join(&e.input);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(_) if e.input == REAL_INPUT => continue,
+            Err(err) => {
+                failures.push(format!(
+                    "{} {}: Can't read {}: {err}",
+                    e.name,
+                    e.part,
+                    path.display()
+                ));
+                continue;
+            }
+        };
+        checked += 1;
+        match day.solve(e.part, &contents, &e.params) {
+            Ok(answer) if answer.to_string() == e.answer => (),
+            Ok(answer) => failures.push(format!(
+                "{} {}: expected {}, got {answer}",
+                e.name, e.part, e.answer
+            )),
+            Err(err) => failures.push(format!("{} {}: {err}", e.name, e.part)),
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    assert!(checked > 0, "No answer checked in {}", dir.display());
+}
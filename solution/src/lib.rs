@@ -0,0 +1,213 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+mod answers;
+mod params;
+pub use answers::{check_answers, expected_answers, Expected};
+pub use params::Params;
+
+/// A day of the calendar: the puzzle input is parsed once and both parts
+/// are solved from the parsed form.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Applies the day's `Params` to the parsed input. Days without any
+    /// option reject every param.
+    fn configure(_input: &mut Self::Input, params: &Params) -> Result<()> {
+        params.allow(&[])
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" | "part1" => Ok(Part::One),
+            "2" | "part2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part {s:?}, expected 1 or 2")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer {
+    value: String,
+    details: Option<String>,
+}
+
+impl Answer {
+    /// Attaches something to show along with the answer, like a drawing of
+    /// how it was found.
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    pub fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer {
+                        value: value.to_string(),
+                        details: None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(u32, u64, u128, usize, i32, i64, i128, isize, String, &str);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The puzzle input isn't in the shape the day expects.
+    Input(String),
+    /// A `Params` value is unknown or can't be parsed.
+    Param(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(msg) => write!(f, "Invalid input: {msg}"),
+            Error::Param(msg) => write!(f, "Invalid parameter: {msg}"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A step of solving a day, as seen by a `Probe`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+/// Gets told when each phase of a solution starts and ends, e.g. to time it.
+pub trait Probe {
+    fn enter(&mut self, phase: Phase);
+    fn leave(&mut self, phase: Phase);
+}
+
+impl Probe for () {
+    fn enter(&mut self, _phase: Phase) {}
+    fn leave(&mut self, _phase: Phase) {}
+}
+
+type Profile = fn(&str, &[Part], &Params, &mut dyn Probe) -> Result<Vec<Result<Answer>>>;
+
+/// Type-erased handle on a `Solution`, so that every day fits in one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    profile: Profile,
+}
+
+impl Day {
+    pub fn new<S: Solution>(number: u8) -> Self {
+        Day {
+            number,
+            profile: profile::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: Part, input: &str, params: &Params) -> Result<Answer> {
+        self.profile(input, &[part], params, &mut ())?.remove(0)
+    }
+
+    /// Parses and configures `input` once and solves each of `parts` from it,
+    /// reporting every phase to `probe`.
+    pub fn profile(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: &Params,
+        probe: &mut dyn Probe,
+    ) -> Result<Vec<Result<Answer>>> {
+        (self.profile)(input, parts, params, probe)
+    }
+}
+
+fn profile<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    params: &Params,
+    probe: &mut dyn Probe,
+) -> Result<Vec<Result<Answer>>> {
+    probe.enter(Phase::Parse);
+    let input = S::parse(input).and_then(|mut input| {
+        S::configure(&mut input, params)?;
+        Ok(input)
+    });
+    probe.leave(Phase::Parse);
+    let input = input?;
+
+    Ok(parts
+        .iter()
+        .map(|part| {
+            probe.enter(Phase::Solve(*part));
+            let answer = match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            };
+            probe.leave(Phase::Solve(*part));
+            answer
+        })
+        .collect())
+}
+
+/// Trimmed lines of the puzzle input.
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.trim().to_string()).collect()
+}
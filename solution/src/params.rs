@@ -0,0 +1,57 @@
+use crate::{Error, Result};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Day specific options, given on the command line as `--param key=value`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Value of `key` parsed as a `T`, if it was given.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>> {
+        self.0
+            .get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| Error::Param(format!("Invalid value {value:?} for {key}")))
+            })
+            .transpose()
+    }
+
+    /// Fails if any key isn't one of `known`.
+    pub fn allow(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            Some(key) if known.is_empty() => Err(Error::Param(format!(
+                "Unknown {key:?}, this day takes none"
+            ))),
+            Some(key) => Err(Error::Param(format!(
+                "Unknown {key:?}, expected one of {}",
+                known.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Params(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}